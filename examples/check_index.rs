@@ -0,0 +1,76 @@
+//! Validates a SCIP index produced by an analyzer against the symbols this
+//! crate expects, printing every mismatch.
+//!
+//! Usage: `cargo run --example check_index -- <index.scip> [rust-analyzer|verus-analyzer]`
+//!
+//! The format defaults to `DEFAULT_FORMAT`. For example, to check the
+//! checked-in rust-analyzer index:
+//!
+//! ```text
+//! cargo run --example check_index -- index-ra.scip rust-analyzer
+//! ```
+//!
+//! Exits with status 1 if there are mismatches, and 2 on bad arguments or an
+//! unreadable index.
+
+use std::path::Path;
+use std::process::ExitCode;
+
+use minimal_scip_issue::scip::{validate_against_scip, Index, Mismatch};
+use minimal_scip_issue::symbols::DEFAULT_FORMAT;
+use minimal_scip_issue::SymbolFormat;
+
+const USAGE: &str = "usage: check_index <index.scip> [rust-analyzer|verus-analyzer]";
+
+/// Reads and decodes the index at `path` and validates it in `format`.
+pub fn check(path: &Path, format: SymbolFormat) -> Result<Vec<Mismatch>, String> {
+    let bytes = std::fs::read(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+    let index = Index::decode(&bytes).map_err(|err| format!("{}: {}", path.display(), err))?;
+    Ok(validate_against_scip(&index, format))
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (path, format) = match args.as_slice() {
+        [path] => (path, DEFAULT_FORMAT),
+        [path, name] => match SymbolFormat::ALL
+            .into_iter()
+            .find(|f| f.tool_name() == name)
+        {
+            Some(format) => (path, format),
+            None => {
+                eprintln!("unknown format: {}", name);
+                eprintln!("{}", USAGE);
+                return ExitCode::from(2);
+            }
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let mismatches = match check(Path::new(path), format) {
+        Ok(mismatches) => mismatches,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::from(2);
+        }
+    };
+    for mismatch in &mismatches {
+        match mismatch {
+            Mismatch::Missing(symbol) => println!("missing: {}", symbol),
+            Mismatch::Extra(symbol) => println!("extra:   {}", symbol),
+        }
+    }
+    println!(
+        "{} mismatch(es) against the expected {} symbols",
+        mismatches.len(),
+        format.tool_name()
+    );
+    if mismatches.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(1)
+    }
+}
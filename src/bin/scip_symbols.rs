@@ -0,0 +1,102 @@
+//! Prints the expected rust-analyzer and verus-analyzer symbol for every impl
+//! method in the crate, flagging verus-analyzer collisions.
+//!
+//! Usage: `cargo run --bin scip-symbols [-- [--format table|json] [--fail-on-duplicates]]`
+//!
+//! With `--format json`, prints the same information as JSON (see
+//! `symbols::symbol_report_json`) instead of a table. With
+//! `--fail-on-duplicates`, exits with a non-zero status if any
+//! verus-analyzer collision exists.
+
+use std::collections::HashSet;
+use std::process::ExitCode;
+
+use minimal_scip_issue::all_crate_impls;
+use minimal_scip_issue::symbols::{find_duplicate_symbols, symbol_report_json};
+use minimal_scip_issue::SymbolFormat;
+
+const USAGE: &str = "usage: scip-symbols [--format table|json] [--fail-on-duplicates]";
+
+fn main() -> ExitCode {
+    let mut fail_on_duplicates = false;
+    let mut json = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--fail-on-duplicates" => fail_on_duplicates = true,
+            "--format" => match args.next().as_deref() {
+                Some("table") => json = false,
+                Some("json") => json = true,
+                other => {
+                    eprintln!("unknown format: {}", other.unwrap_or("(none)"));
+                    eprintln!("{}", USAGE);
+                    return ExitCode::from(2);
+                }
+            },
+            _ => {
+                eprintln!("unknown argument: {}", arg);
+                eprintln!("{}", USAGE);
+                return ExitCode::from(2);
+            }
+        }
+    }
+
+    let impls = all_crate_impls();
+    let collisions = find_duplicate_symbols(&impls, SymbolFormat::VerusAnalyzer);
+    if json {
+        print!("{}", symbol_report_json(&impls));
+        return exit_code(fail_on_duplicates && !collisions.is_empty());
+    }
+    let colliding: HashSet<usize> = collisions
+        .iter()
+        .flat_map(|(_, members)| members.iter().copied())
+        .collect();
+
+    let rows: Vec<(String, String)> = impls
+        .iter()
+        .map(|d| {
+            (
+                d.to_symbol(SymbolFormat::RustAnalyzer),
+                d.to_symbol(SymbolFormat::VerusAnalyzer),
+            )
+        })
+        .collect();
+    let width = rows
+        .iter()
+        .map(|(ra, _)| ra.len())
+        .chain(["rust-analyzer".len()])
+        .max()
+        .unwrap_or(0);
+
+    println!("{:<width$}  verus-analyzer", "rust-analyzer");
+    for (idx, (ra, va)) in rows.iter().enumerate() {
+        if colliding.contains(&idx) {
+            println!("{:<width$}  {}  <-- DUPLICATE", ra, va);
+        } else {
+            println!("{:<width$}  {}", ra, va);
+        }
+    }
+
+    println!();
+    println!(
+        "{} impls, {} verus-analyzer collision groups",
+        impls.len(),
+        collisions.len()
+    );
+    for (symbol, members) in &collisions {
+        println!("  {} ({} impls)", symbol, members.len());
+        for &idx in members {
+            println!("    {}", rows[idx].0);
+        }
+    }
+
+    exit_code(fail_on_duplicates && !collisions.is_empty())
+}
+
+fn exit_code(failed: bool) -> ExitCode {
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -1,16 +1,103 @@
 //! Minimal example demonstrating SCIP symbol format differences.
 //!
-//! This crate shows four cases:
+//! This crate shows the following cases:
 //! 1. Owned Self: `impl Neg for Scalar` - works correctly in both tools
 //! 2. Reference Self: `impl Neg for &Scalar` - verus-analyzer omits the Self type
 //! 3. Duplicate symbols (Mul): Two different `Mul` impls produce identical symbols
 //! 4. Duplicate symbols (From): Generic type params lost, causing duplicates
+//! 5. Add: owned vs reference operands, with reference-Self impls colliding
+//! 6. Sub: componentwise subtraction, mirroring the Case 3 setup
+//! 7. Inherent impls: checked arithmetic methods on `Scalar` and `Point`
+//! 8. Const generics: `Vector<const N: usize>` - verus-analyzer drops `<N>`
+//! 9. Lifetimes: `impl<'a> Neg for Ref<'a>` - verus-analyzer drops the lifetime
+//! 10. `where` clauses: a generic `From<Scalar> for Container<T>` - collides with Case 4
+//! 11. Associated consts: `impl Named for Scalar` - symbols end in `NAME.`
+//! 12. Blanket impls: `impl<T> Double for T` - verus-analyzer has no Self to name
+//! 13. Supertraits: `trait Magnitude: Neg` - the supertrait is not in the symbol
+//! 14. Default methods: only overriding impls get their own method symbol
+//! 15. Non-generic From: a negative control for Case 4 (until Case 42 adds a second `From` for `Scalar`)
+//! 16. Display: std trait methods (`fmt`) on user types
+//! 17. FromStr: a `from_str` method plus an associated `Err` type
+//! 18. Index/IndexMut: operator impls with a `usize` trait argument
+//! 19. IntoIterator: owned vs reference Self again, plus `Item`/`IntoIter` types
+//! 20. Two type parameters: `Pair<A, B>` - collides like Case 4
+//! 21. Nested generics: `Container<Container<TypeA>>` - also collides with Case 4
+//! 22. Method-level generics: `convert<U>` - the parameter gets its own symbol
+//! 23. Tuple Self: `(Scalar, Scalar)` - verus-analyzer drops it like a reference
+//! 24. Array Self: `[Scalar; 2]` - dropped too, colliding with Case 23
+//! 25. PartialEq/Eq: hand-written rather than derived, so `eq` gets a symbol
+//! 26. PartialOrd/Ord: `partial_cmp` and `cmp` on `Scalar`
+//! 27. Hash: a std trait method with its own `H: Hasher` parameter
+//! 28. Fallible arithmetic: `try_mul`/`try_scale` plus an `ArithmeticError` type
+//! 29. TryFrom: a narrowing `i64` conversion with an associated `Error` type
+//! 30. Neg for `Point`: Cases 1/2 on another type - `&Point` collides with Case 2
+//! 31. Compound assignment: `AddAssign`/`MulAssign` - `&mut self` receivers
+//! 32. Sum/Product: trait methods generic over the iterator type `I`
+//! 33. Inherent associated consts: `Scalar::ZERO` and `Point::ORIGIN`
+//! 34. PhantomData: `Tagged<T>` - verus-analyzer drops the marker parameter
+//! 35. Closure bounds and HRTBs: `F: Fn(i32) -> i32` and `for<'a>` - neither is in the symbol
+//! 36. Non-`Copy` Self: `impl Neg for BigScalar` - move semantics do not affect the symbol
+//! 37. Deref: `deref` plus an associated `Target` type, reached through auto-deref
+//! 38. Enum Self: `Sign` - named exactly like a struct in both tools
+//! 39. Primitive Self: `impl Mul<Scalar> for i32` - verus-analyzer drops `i32`, colliding with Case 3
+//! 40. Owned Mul: the by-value counterpart of Case 3 - no `Mul#mul().` collision
+//! 41. Hand-written generic Clone: `impl<T: Clone> Clone for Container<T>` - the bound is not in the symbol
+//! 42. `From<Point> for Scalar`: no collision with Case 4, but one with Case 15
+//! 43. `impl Mul for Scalar`: a control for the Case 3 `Mul#mul().` collision
+//! 44. A different `Output`: `Mul<Scalar> for &Scalar` - associated types do not disambiguate Case 3
+//! 45. Smart-pointer Self: `Box<Scalar>` is an ADT, so verus-analyzer keeps `Box` but drops `Scalar`
+//! 46. Default: an associated function without `self` - same symbol shape as a method
+//! 47. Div/Rem: operators that fail on zero, built on fallible `try_div`/`try_rem`
+//! 48. PartialEq<i32>: a heterogeneous comparison (collides with Case 25)
+//! 49. `From<i32> for Scalar<i64>`: Case 4's generic loss, on the Self type
+//! 50. Trait objects: `dyn Shape` inside and as the Self type
+//! 51. Iterator: a `next` method and an `Item` associated type
+//! 52. Raw-pointer Self: `*const Scalar` - dropped by verus-analyzer like Case 2's `&Scalar`
+//! 53. Overflow modes: `Wrapping`/`Saturating` wrappers sharing a `ScalarArith` trait
+//! 54. `Mul<&Scalar> for &Scalar`: references on both sides - still just `Mul#mul().`
+//! 55. `From<&Point> for Container<TypeA>`: a new source type, yet still `Container#From#from().`
+//! 56. AsRef/AsMut: `as_ref`/`as_mut` borrowing the inner `i32`
+//! 57. Nested module: `geometry::Vec2` - both tools prefix the descriptor with `geometry/`
+//! 58. Binary/Octal: more `fmt` methods, told apart from Case 16's by the trait name
+//! 59. `Mul<Scalar> for &Point`: reference Self, owned argument - the same `Mul#mul().` as Case 3
+//! 60. Ord for `Point`: lexicographic `cmp` - the same symbol shape as Case 26's
+//! 61. Add for `Point`: the owned counterpart of Case 6's `Sub`
+//! 62. `ToPoint`: a method returning another type - return types are not in symbols
+//! 63. Generic Self with passthrough: `impl<T> Neg for Container<T>` - one symbol for every `T`
+//! 64. `TryFrom<&Scalar> for Sign`: a fallible conversion next to Case 38's infallible one
+//! 65. `Mul<Container<TypeA/B>> for Scalar`: Case 4's generic loss on a `Mul` argument
+//!
+//! The [`symbols`] module builds the expected symbol strings programmatically,
+//! and [`scip`] assembles them into the SCIP index each analyzer should emit.
+
+use std::cmp::Ordering;
+use std::fmt::{self, Binary, Display, Octal};
+use std::hash::{Hash, Hasher};
+use std::iter::{Product, Sum};
+use std::marker::PhantomData;
+use std::num::{IntErrorKind, ParseIntError, TryFromIntError};
+use std::ops::{Add, AddAssign, Deref, Div, Index, IndexMut, Mul, MulAssign, Neg, Rem, Sub};
+use std::str::FromStr;
+
+pub mod scip;
+pub mod symbols;
+#[cfg(test)]
+mod testgen;
 
-use std::ops::{Mul, Neg};
+pub use symbols::{ImplDescriptor, SymbolFormat};
 
 /// A simple scalar type for demonstration.
+///
+/// The integer type defaults to `i32`, so plain `Scalar` keeps meaning
+/// `Scalar<i32>`. The Case 1-4 impls stay on `Scalar<i32>` so their symbols keep
+/// matching the checked-in indexes; the `Add`/`Sub` impls are generic.
+///
+/// Generic `Neg`/`Mul` impls are deferred. Making Cases 1 and 43 generic would
+/// change their symbols away from the checked-in indexes, and a second, generic
+/// impl next to them would leave the type of an unsuffixed `-Scalar(5)`
+/// ambiguous.
 #[derive(Clone, Copy, Debug)]
-pub struct Scalar(pub i32);
+pub struct Scalar<T = i32>(pub T);
 
 /// A simple point type for demonstration.
 #[derive(Clone, Copy, Debug)]
@@ -23,11 +110,13 @@ pub struct Point(pub i32, pub i32);
 /// Expected symbols:
 /// - rust-analyzer: `impl#[Scalar][Neg]neg().`
 /// - verus-analyzer: `Scalar#Neg#neg().`
+///
+/// Panics on overflow; use [`Scalar::checked_neg`] to handle it.
 impl Neg for Scalar {
     type Output = Scalar;
 
     fn neg(self) -> Scalar {
-        Scalar(-self.0)
+        self.checked_neg().expect("attempt to negate with overflow")
     }
 }
 
@@ -38,11 +127,13 @@ impl Neg for Scalar {
 /// Expected symbols:
 /// - rust-analyzer: `impl#[`&Scalar`][Neg]neg().`
 /// - verus-analyzer: `Neg#neg().`  <-- Missing the `&Scalar` Self type!
+///
+/// Panics on overflow; use [`Scalar::checked_neg`] to handle it.
 impl Neg for &Scalar {
     type Output = Scalar;
 
     fn neg(self) -> Scalar {
-        Scalar(-self.0)
+        self.checked_neg().expect("attempt to negate with overflow")
     }
 }
 
@@ -55,11 +146,14 @@ impl Neg for &Scalar {
 /// Expected symbols:
 /// - rust-analyzer: `impl#[`&Point`][`Mul<&Scalar>`]mul().`
 /// - verus-analyzer: `Mul#mul().`
+///
+/// Panics on overflow; use [`Point::checked_mul_scalar`] to handle it.
 impl Mul<&Scalar> for &Point {
     type Output = Point;
 
     fn mul(self, scalar: &Scalar) -> Point {
-        Point(self.0 * scalar.0, self.1 * scalar.0)
+        self.checked_mul_scalar(*scalar)
+            .expect("attempt to multiply with overflow")
     }
 }
 
@@ -68,11 +162,15 @@ impl Mul<&Scalar> for &Point {
 /// Expected symbols:
 /// - rust-analyzer: `impl#[`&Scalar`][`Mul<&Point>`]mul().`
 /// - verus-analyzer: `Mul#mul().`  <-- DUPLICATE! Same as above!
+///
+/// Panics on overflow; use [`Point::checked_mul_scalar`] to handle it.
 impl Mul<&Point> for &Scalar {
     type Output = Point;
 
     fn mul(self, point: &Point) -> Point {
-        Point(self.0 * point.0, self.0 * point.1)
+        point
+            .checked_mul_scalar(*self)
+            .expect("attempt to multiply with overflow")
     }
 }
 
@@ -116,52 +214,3584 @@ impl From<&Scalar> for Container<TypeB> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+// =============================================================================
+// Case 5: Add - owned vs reference operands on a commutative operator
+// =============================================================================
 
-    #[test]
-    fn test_neg_owned() {
-        let s = Scalar(5);
-        assert_eq!((-s).0, -5);
+/// Add two scalars.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`Scalar<T>`][`Add<Self>`]add().`
+/// - verus-analyzer: `Scalar#Add#add().`  <-- Generic Self loses its `<T>`!
+///
+/// rust-analyzer spells out the defaulted `Rhs` parameter as `Self`, just like
+/// the `impl#[i32][`Mul<Self>`]mul().` symbol for core in `index-ra.json`.
+///
+/// Overflow behaves like the underlying integer type.
+impl<T: Add<Output = T>> Add for Scalar<T> {
+    type Output = Scalar<T>;
+
+    fn add(self, rhs: Scalar<T>) -> Scalar<T> {
+        Scalar(self.0 + rhs.0)
     }
+}
 
-    #[test]
-    fn test_neg_ref() {
-        let s = Scalar(5);
-        assert_eq!((-&s).0, -5);
+/// Add a scalar reference to a scalar reference.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`&Scalar<T>`][`Add<&Scalar<T>>`]add().`
+/// - verus-analyzer: `Add#add().`
+impl<T: Add<Output = T> + Copy> Add<&Scalar<T>> for &Scalar<T> {
+    type Output = Scalar<T>;
+
+    fn add(self, rhs: &Scalar<T>) -> Scalar<T> {
+        Scalar(self.0 + rhs.0)
     }
+}
 
-    #[test]
-    fn test_mul_point_by_scalar() {
-        let p = Point(2, 3);
-        let s = Scalar(4);
-        let result = &p * &s;
-        assert_eq!(result.0, 8);
-        assert_eq!(result.1, 12);
+/// Add an owned scalar to a scalar reference.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`&Scalar<T>`][`Add<Scalar<T>>`]add().`
+/// - verus-analyzer: `Add#add().`  <-- DUPLICATE! Same as above!
+impl<T: Add<Output = T> + Copy> Add<Scalar<T>> for &Scalar<T> {
+    type Output = Scalar<T>;
+
+    fn add(self, rhs: Scalar<T>) -> Scalar<T> {
+        Scalar(self.0 + rhs.0)
     }
+}
 
-    #[test]
-    fn test_mul_scalar_by_point() {
-        let s = Scalar(4);
-        let p = Point(2, 3);
-        let result = &s * &p;
-        assert_eq!(result.0, 8);
-        assert_eq!(result.1, 12);
+// =============================================================================
+// Case 6: Sub - a counterpart to the Case 3 `Mul` impls
+// =============================================================================
+
+/// Subtract one scalar from another.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`Scalar<T>`][`Sub<Self>`]sub().`
+/// - verus-analyzer: `Scalar#Sub#sub().`
+impl<T: Sub<Output = T>> Sub for Scalar<T> {
+    type Output = Scalar<T>;
+
+    fn sub(self, rhs: Scalar<T>) -> Scalar<T> {
+        Scalar(self.0 - rhs.0)
     }
+}
 
-    #[test]
-    fn test_from_scalar_to_container_a() {
-        let s = Scalar(5);
-        let _c: Container<TypeA> = Container::from(&s);
-        // Just verify it compiles and runs
+/// Subtract one point from another, componentwise.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][`Sub<Self>`]sub().`
+/// - verus-analyzer: `Point#Sub#sub().`
+///
+/// Panics on overflow.
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        self.0
+            .checked_sub(rhs.0)
+            .zip(self.1.checked_sub(rhs.1))
+            .map(|(x, y)| Point(x, y))
+            .expect("attempt to subtract with overflow")
     }
+}
 
-    #[test]
-    fn test_from_scalar_to_container_b() {
-        let s = Scalar(5);
-        let _c: Container<TypeB> = Container::from(&s);
-        // Just verify it compiles and runs
+/// Subtract a scalar reference from both components of a point reference.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`&Point`][`Sub<&Scalar>`]sub().`
+/// - verus-analyzer: `Sub#sub().`
+///
+/// This is unique for now, but a `Sub<&Point> for &Scalar` impl would collide
+/// with it exactly like the Case 3 `Mul` impls.
+///
+/// Panics on overflow.
+impl Sub<&Scalar> for &Point {
+    type Output = Point;
+
+    fn sub(self, scalar: &Scalar) -> Point {
+        self.0
+            .checked_sub(scalar.0)
+            .zip(self.1.checked_sub(scalar.0))
+            .map(|(x, y)| Point(x, y))
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+// =============================================================================
+// Case 7: Inherent impls - checked arithmetic
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar]checked_neg().`, `impl#[Scalar]checked_mul().`
+/// - verus-analyzer: `Scalar#checked_neg().`, `Scalar#checked_mul().`
+impl Scalar {
+    /// Negates the scalar, returning `None` on overflow.
+    pub fn checked_neg(self) -> Option<Scalar> {
+        self.0.checked_neg().map(Scalar)
+    }
+
+    /// Multiplies two scalars, returning `None` on overflow.
+    pub fn checked_mul(self, rhs: Scalar) -> Option<Scalar> {
+        self.0.checked_mul(rhs.0).map(Scalar)
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point]checked_mul_scalar().`
+/// - verus-analyzer: `Point#checked_mul_scalar().`
+impl Point {
+    /// Multiplies both components by a scalar, returning `None` on overflow.
+    pub fn checked_mul_scalar(self, scalar: Scalar) -> Option<Point> {
+        Some(Point(
+            self.0.checked_mul(scalar.0)?,
+            self.1.checked_mul(scalar.0)?,
+        ))
+    }
+}
+
+// =============================================================================
+// Case 8: Const generics - the const parameter is part of the Self type
+// =============================================================================
+
+/// A fixed-length vector, generic over its length.
+#[derive(Clone, Copy, Debug)]
+pub struct Vector<const N: usize>(pub [i32; N]);
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`Vector<N>`][Neg]neg().`
+/// - verus-analyzer: `Vector#Neg#neg().`  <-- The const parameter is lost!
+///
+/// A single generic impl covers every `N`, so this does not collide by itself,
+/// but separate impls for `Vector<2>` and `Vector<3>` would both map to
+/// `Vector#Neg#neg().` under verus-analyzer.
+impl<const N: usize> Neg for Vector<N> {
+    type Output = Vector<N>;
+
+    fn neg(self) -> Vector<N> {
+        Vector(self.0.map(|x| -x))
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`Vector<N>`][`Add<Self>`]add().`
+/// - verus-analyzer: `Vector#Add#add().`
+impl<const N: usize> Add for Vector<N> {
+    type Output = Vector<N>;
+
+    fn add(self, rhs: Vector<N>) -> Vector<N> {
+        Vector(std::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+// =============================================================================
+// Case 9: Lifetime parameters in the Self type
+// =============================================================================
+
+/// A borrowed scalar with an explicit named lifetime.
+#[derive(Clone, Copy, Debug)]
+pub struct Ref<'a>(pub &'a Scalar);
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`Ref<'a>`][Neg]neg().`
+/// - verus-analyzer: `Ref#Neg#neg().`  <-- The lifetime is dropped with the other generics
+impl<'a> Neg for Ref<'a> {
+    type Output = Scalar;
+
+    fn neg(self) -> Scalar {
+        -*self.0
+    }
+}
+
+// =============================================================================
+// Case 10: `where`-clause bounds on a generic impl
+// =============================================================================
+
+/// Convert from Scalar to a default-initialized Container<T>.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`Container<T>`][`From<Scalar>`]from().`
+/// - verus-analyzer: `Container#From#from().`  <-- DUPLICATE of both Case 4 impls!
+///
+/// Neither tool folds the `T: Default` bound into the symbol. rust-analyzer
+/// keeps the impl distinct through the `<T>` and `From<Scalar>` arguments.
+impl<T> From<Scalar> for Container<T>
+where
+    T: Default,
+{
+    fn from(_s: Scalar) -> Self {
+        Container {
+            value: T::default(),
+        }
+    }
+}
+
+// =============================================================================
+// Case 11: Associated consts
+// =============================================================================
+
+/// A type with a human-readable name.
+///
+/// Expected symbol for the trait's const (both tools): `Named#NAME.`
+pub trait Named {
+    const NAME: &'static str;
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][Named]NAME.`
+/// - verus-analyzer: `Scalar#Named#NAME.`
+///
+/// Associated consts use the term suffix `.` instead of the method suffix `().`;
+/// otherwise they follow the same impl naming as methods in each tool.
+impl Named for Scalar {
+    const NAME: &'static str = "Scalar";
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][Named]NAME.`
+/// - verus-analyzer: `Point#Named#NAME.`
+impl Named for Point {
+    const NAME: &'static str = "Point";
+}
+
+// =============================================================================
+// Case 12: Blanket impls
+// =============================================================================
+
+/// Doubles a value by adding it to itself.
+///
+/// Expected symbol for the trait's method (both tools): `Double#double().`
+pub trait Double {
+    fn double(self) -> Self;
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[T][Double]double().`
+/// - verus-analyzer: `Double#double().`  <-- Same as the trait's own method!
+///
+/// The Self type is a type parameter rather than an ADT, so verus-analyzer
+/// drops it, and the impl method becomes indistinguishable from the trait
+/// method it implements. There is a single impl, so applying it to several
+/// types (`Scalar`, `i32`, ...) does not add more symbols in either tool.
+impl<T: Add<Output = T> + Copy> Double for T {
+    fn double(self) -> Self {
+        self + self
+    }
+}
+
+// =============================================================================
+// Case 13: Supertraits
+// =============================================================================
+
+/// The absolute size of a value that can also be negated.
+///
+/// Expected symbol for the trait (both tools): `Magnitude#`
+///
+/// Neither tool encodes the `Neg` supertrait in any symbol; it only shows up
+/// in the trait's signature documentation (`pub trait Magnitude: Neg`).
+pub trait Magnitude: Neg {
+    fn magnitude(&self) -> i32;
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][Magnitude]magnitude().`
+/// - verus-analyzer: `Scalar#Magnitude#magnitude().`
+///
+/// Panics for `Scalar(i32::MIN)` in debug builds, like `i32::abs`.
+impl Magnitude for Scalar {
+    fn magnitude(&self) -> i32 {
+        self.0.abs()
+    }
+}
+
+// =============================================================================
+// Case 14: Default trait methods
+// =============================================================================
+
+/// A short label for a value.
+///
+/// Expected symbol for the default method (both tools): `Describe#label().`
+pub trait Describe {
+    fn label(&self) -> String {
+        "unknown".into()
+    }
+}
+
+/// Overrides the default method.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][Describe]label().`
+/// - verus-analyzer: `Scalar#Describe#label().`
+impl Describe for Scalar {
+    fn label(&self) -> String {
+        format!("scalar {}", self.0)
+    }
+}
+
+/// Uses the default method.
+///
+/// No method symbol is emitted for this impl in either tool; calls to
+/// `Point(1, 2).label()` reference the trait's `Describe#label().`.
+impl Describe for Point {}
+
+// =============================================================================
+// Case 15: Non-generic From impls - a negative control for Case 4
+// =============================================================================
+
+/// Convert from i32 to Scalar.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`From<i32>`]from().`
+/// - verus-analyzer: `Scalar#From#from().`  <-- Collides with Cases 42 and 49!
+impl From<i32> for Scalar {
+    fn from(value: i32) -> Self {
+        Scalar(value)
+    }
+}
+
+/// Convert from a tuple to Point.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][`From<(i32, i32)>`]from().`
+/// - verus-analyzer: `Point#From#from().`
+///
+/// The Self types are distinct non-generic ADTs, so unlike Case 4 these do not
+/// collide under verus-analyzer.
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Self {
+        Point(x, y)
+    }
+}
+
+// =============================================================================
+// Case 16: Display - a std trait with a `fmt` method
+// =============================================================================
+
+/// Formats as `Scalar(5)`.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][Display]fmt().`
+/// - verus-analyzer: `Scalar#Display#fmt().`
+impl Display for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Scalar({})", self.0)
+    }
+}
+
+/// Formats as `Point(2, 3)`.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][Display]fmt().`
+/// - verus-analyzer: `Point#Display#fmt().`
+impl Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Point({}, {})", self.0, self.1)
+    }
+}
+
+// =============================================================================
+// Case 17: FromStr - a method plus an associated `Err` type
+// =============================================================================
+
+/// The error returned when parsing a [`Scalar`] fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseScalarError(ParseIntError);
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[ParseScalarError]kind().`
+/// - verus-analyzer: `ParseScalarError#kind().`
+impl ParseScalarError {
+    /// Why the integer part failed to parse, e.g. [`IntErrorKind::PosOverflow`].
+    pub fn kind(&self) -> &IntErrorKind {
+        self.0.kind()
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[ParseScalarError][Display]fmt().`
+/// - verus-analyzer: `ParseScalarError#Display#fmt().`
+impl Display for ParseScalarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid scalar: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseScalarError {}
+
+/// Parses `5` or `Scalar(5)`.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][FromStr]from_str().`
+/// - verus-analyzer: `Scalar#FromStr#from_str().`
+///
+/// Expected symbols for the associated `Err` type:
+/// - rust-analyzer: `impl#[Scalar][FromStr]Err#`
+/// - verus-analyzer: `Scalar#FromStr#[Err]`
+impl FromStr for Scalar {
+    type Err = ParseScalarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let inner = s
+            .strip_prefix("Scalar(")
+            .and_then(|rest| rest.strip_suffix(')'))
+            .unwrap_or(s);
+        inner.trim().parse().map(Scalar).map_err(ParseScalarError)
+    }
+}
+
+/// The error returned when parsing a [`Point`] fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParsePointError {
+    /// The input is not of the form `(x, y)` or `Point(x, y)`.
+    Malformed,
+    /// A component is not a valid `i32`.
+    Component(ParseIntError),
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[ParsePointError][Display]fmt().`
+/// - verus-analyzer: `ParsePointError#Display#fmt().`
+impl Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePointError::Malformed => write!(f, "expected `(x, y)` or `Point(x, y)`"),
+            ParsePointError::Component(err) => write!(f, "invalid point component: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ParsePointError {}
+
+/// Parses `(2, 3)` or `Point(2, 3)`.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][FromStr]from_str().`
+/// - verus-analyzer: `Point#FromStr#from_str().`
+///
+/// Expected symbols for the associated `Err` type:
+/// - rust-analyzer: `impl#[Point][FromStr]Err#`
+/// - verus-analyzer: `Point#FromStr#[Err]`
+impl FromStr for Point {
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix("Point").unwrap_or(s);
+        let inner = s
+            .strip_prefix('(')
+            .and_then(|rest| rest.strip_suffix(')'))
+            .ok_or(ParsePointError::Malformed)?;
+        let (x, y) = inner.split_once(',').ok_or(ParsePointError::Malformed)?;
+        let x = x.trim().parse().map_err(ParsePointError::Component)?;
+        let y = y.trim().parse().map_err(ParsePointError::Component)?;
+        Ok(Point(x, y))
+    }
+}
+
+// =============================================================================
+// Case 18: Index and IndexMut - operators with an associated `Output`
+// =============================================================================
+
+/// `p[0]` is the x component and `p[1]` the y component.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][`Index<usize>`]index().`
+/// - verus-analyzer: `Point#Index#index().`
+///
+/// Expected symbols for the associated `Output` type:
+/// - rust-analyzer: `impl#[Point][`Index<usize>`]Output#`
+/// - verus-analyzer: `Point#Index#[Output]`
+///
+/// Panics if the index is not 0 or 1.
+impl Index<usize> for Point {
+    type Output = i32;
+
+    fn index(&self, index: usize) -> &i32 {
+        match index {
+            0 => &self.0,
+            1 => &self.1,
+            _ => panic!(
+                "index out of bounds: a Point has 2 components but the index is {}",
+                index
+            ),
+        }
     }
 }
 
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][`IndexMut<usize>`]index_mut().`
+/// - verus-analyzer: `Point#IndexMut#index_mut().`
+///
+/// Panics if the index is not 0 or 1.
+impl IndexMut<usize> for Point {
+    fn index_mut(&mut self, index: usize) -> &mut i32 {
+        match index {
+            0 => &mut self.0,
+            1 => &mut self.1,
+            _ => panic!(
+                "index out of bounds: a Point has 2 components but the index is {}",
+                index
+            ),
+        }
+    }
+}
+
+// =============================================================================
+// Case 19: IntoIterator - associated types on owned and reference Self
+// =============================================================================
+
+/// Iterates over the x and y components.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][IntoIterator]into_iter().`
+/// - verus-analyzer: `Point#IntoIterator#into_iter().`
+///
+/// Expected symbols for the associated types:
+/// - rust-analyzer: `impl#[Point][IntoIterator]Item#`, `impl#[Point][IntoIterator]IntoIter#`
+/// - verus-analyzer: `Point#IntoIterator#[Item]`, `Point#IntoIterator#[IntoIter]`
+impl IntoIterator for Point {
+    type Item = i32;
+    type IntoIter = std::array::IntoIter<i32, 2>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        [self.0, self.1].into_iter()
+    }
+}
+
+/// Iterates over copies of the x and y components.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`&Point`][IntoIterator]into_iter().`
+/// - verus-analyzer: `IntoIterator#into_iter().`  <-- Missing the `&Point` Self type!
+///
+/// Expected symbols for the associated types:
+/// - rust-analyzer: `impl#[`&Point`][IntoIterator]Item#`, `impl#[`&Point`][IntoIterator]IntoIter#`
+/// - verus-analyzer: `IntoIterator#[Item]`, `IntoIterator#[IntoIter]`
+///
+/// As in Case 2, verus-analyzer drops the reference Self type, and it does so
+/// for the associated types as well as the method.
+impl IntoIterator for &Point {
+    type Item = i32;
+    type IntoIter = std::array::IntoIter<i32, 2>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        [self.0, self.1].into_iter()
+    }
+}
+
+// =============================================================================
+// Case 20: Two generic parameters - extending Case 4
+// =============================================================================
+
+/// A generic pair with two type parameters.
+#[derive(Debug)]
+pub struct Pair<A, B> {
+    pub first: A,
+    pub second: B,
+}
+
+/// Convert from Scalar to Pair<TypeA, TypeB>.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`Pair<TypeA, TypeB>`][`From<&Scalar>`]from().`
+/// - verus-analyzer: `Pair#From#from().`
+impl From<&Scalar> for Pair<TypeA, TypeB> {
+    fn from(_s: &Scalar) -> Self {
+        Pair {
+            first: TypeA,
+            second: TypeB,
+        }
+    }
+}
+
+/// Convert from Scalar to Pair<TypeB, TypeA>.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`Pair<TypeB, TypeA>`][`From<&Scalar>`]from().`
+/// - verus-analyzer: `Pair#From#from().`  <-- DUPLICATE! Same as above!
+///
+/// Swapping the arguments is enough to collide: verus-analyzer drops both.
+impl From<&Scalar> for Pair<TypeB, TypeA> {
+    fn from(_s: &Scalar) -> Self {
+        Pair {
+            first: TypeB,
+            second: TypeA,
+        }
+    }
+}
+
+// =============================================================================
+// Case 21: Nested generics
+// =============================================================================
+
+/// Convert from Scalar to Container<Container<TypeA>>.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`Container<Container<TypeA>>`][`From<&Scalar>`]from().`
+/// - verus-analyzer: `Container#From#from().`  <-- DUPLICATE of Case 4 and Case 10!
+///
+/// rust-analyzer keeps the nested generic arguments. verus-analyzer keeps only
+/// the outermost ADT name, so this collides with the flat `Container<TypeA>`.
+impl From<&Scalar> for Container<Container<TypeA>> {
+    fn from(_s: &Scalar) -> Self {
+        Container {
+            value: Container { value: TypeA },
+        }
+    }
+}
+
+// =============================================================================
+// Case 22: Method-level generic parameters
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar]convert().`
+/// - verus-analyzer: `Scalar#convert().`
+///
+/// The method's type parameter is not part of the method symbol. Both tools
+/// give it a separate type-parameter descriptor nested under the method:
+/// `impl#[Scalar]convert().[U]` and `Scalar#convert().[U]` respectively.
+impl Scalar {
+    /// Converts into any type that can be built from a scalar.
+    pub fn convert<U: From<Scalar>>(self) -> U {
+        U::from(self)
+    }
+}
+
+// =============================================================================
+// Case 23: Tuple Self type
+// =============================================================================
+
+/// Elementwise negation for compound types.
+///
+/// The orphan rule forbids `impl Neg for (Scalar, Scalar)`, because tuples are
+/// never local types, so this local trait with the same shape as `Neg` stands
+/// in for it. The Self type, which is what the symbols are about, is the same.
+pub trait ElementwiseNeg {
+    type Output;
+
+    fn neg(self) -> Self::Output;
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`(Scalar, Scalar)`][ElementwiseNeg]neg().`
+/// - verus-analyzer: `ElementwiseNeg#neg().`  <-- The tuple Self type is dropped!
+///
+/// A tuple is not an ADT, so verus-analyzer cannot name it at all, exactly as
+/// with the `&Scalar` Self type in Case 2.
+impl ElementwiseNeg for (Scalar, Scalar) {
+    type Output = (Scalar, Scalar);
+
+    fn neg(self) -> (Scalar, Scalar) {
+        (-self.0, -self.1)
+    }
+}
+
+// =============================================================================
+// Case 24: Fixed-size array Self type
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`[Scalar; 2]`][ElementwiseNeg]neg().`
+/// - verus-analyzer: `ElementwiseNeg#neg().`  <-- DUPLICATE of the Case 23 tuple impl!
+///
+/// rust-analyzer keeps the array length in the Self type. verus-analyzer drops
+/// the whole array type, length included, since it is not an ADT. As with
+/// tuples, the orphan rule rules out implementing `Neg` itself here.
+impl ElementwiseNeg for [Scalar; 2] {
+    type Output = [Scalar; 2];
+
+    fn neg(self) -> [Scalar; 2] {
+        self.map(|s| -s)
+    }
+}
+
+// =============================================================================
+// Case 25: PartialEq and Eq - manual impls instead of derives
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`PartialEq<Self>`]eq().`
+/// - verus-analyzer: `Scalar#PartialEq#eq().` <-- Collides with Case 48!
+///
+/// A `#[derive(PartialEq)]` impl would have the same descriptor, but its
+/// definition lives in a macro expansion, so neither tool emits a definition
+/// for it. The checked-in indexes show this for the `Clone`/`Copy`/`Debug`
+/// derives: they only contain references to the derive macros.
+impl PartialEq for Scalar {
+    fn eq(&self, other: &Scalar) -> bool {
+        self.0 == other.0
+    }
+}
+
+/// `Eq` has no methods, so only the impl block itself gets a symbol:
+/// `impl#[Scalar][Eq]` in rust-analyzer, and none in verus-analyzer.
+impl Eq for Scalar {}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][`PartialEq<Self>`]eq().`
+/// - verus-analyzer: `Point#PartialEq#eq().`
+impl PartialEq for Point {
+    fn eq(&self, other: &Point) -> bool {
+        self.0 == other.0 && self.1 == other.1
+    }
+}
+
+/// Expected symbols: `impl#[Point][Eq]` in rust-analyzer, none in verus-analyzer.
+impl Eq for Point {}
+
+// =============================================================================
+// Case 26: PartialOrd and Ord
+// =============================================================================
+
+/// Orders scalars by their inner value.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`PartialOrd<Self>`]partial_cmp().`
+/// - verus-analyzer: `Scalar#PartialOrd#partial_cmp().`
+impl PartialOrd for Scalar {
+    fn partial_cmp(&self, other: &Scalar) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][Ord]cmp().`
+/// - verus-analyzer: `Scalar#Ord#cmp().`
+///
+/// `Ord` has no type parameters, so rust-analyzer has nothing to quote here
+/// while `PartialOrd<Self>` above needs backticks.
+impl Ord for Scalar {
+    fn cmp(&self, other: &Scalar) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+// =============================================================================
+// Case 27: Hash - a trait method with a method-level generic
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][Hash]hash().`
+/// - verus-analyzer: `Scalar#Hash#hash().`
+///
+/// As in Case 22, the method-level `H: Hasher` parameter is not part of the
+/// method symbol; it gets a nested descriptor in both tools,
+/// `impl#[Scalar][Hash]hash().[H]` and `Scalar#Hash#hash().[H]`.
+impl Hash for Scalar {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][Hash]hash().`
+/// - verus-analyzer: `Point#Hash#hash().`
+impl Hash for Point {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+        self.1.hash(state);
+    }
+}
+
+// =============================================================================
+// Case 28: Fallible arithmetic - inherent methods returning a typed error
+// =============================================================================
+
+/// The error returned by the fallible arithmetic methods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArithmeticError {
+    /// The result does not fit in an `i32`.
+    Overflow,
+    /// The divisor is zero.
+    DivByZero,
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[ArithmeticError][Display]fmt().`
+/// - verus-analyzer: `ArithmeticError#Display#fmt().`
+impl Display for ArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArithmeticError::Overflow => f.write_str("arithmetic overflow"),
+            ArithmeticError::DivByZero => f.write_str("division by zero"),
+        }
+    }
+}
+
+impl std::error::Error for ArithmeticError {}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar]try_mul().`
+/// - verus-analyzer: `Scalar#try_mul().`
+///
+/// A second inherent impl block on `Scalar` contributes to the same namespace
+/// as Case 7; only the method names need to differ.
+impl Scalar {
+    /// Multiplies two scalars, returning [`ArithmeticError::Overflow`] on overflow.
+    pub fn try_mul(self, rhs: Scalar) -> Result<Scalar, ArithmeticError> {
+        self.checked_mul(rhs).ok_or(ArithmeticError::Overflow)
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point]try_scale().`
+/// - verus-analyzer: `Point#try_scale().`
+impl Point {
+    /// Multiplies both components by `s`, returning
+    /// [`ArithmeticError::Overflow`] on overflow.
+    pub fn try_scale(self, s: Scalar) -> Result<Point, ArithmeticError> {
+        self.checked_mul_scalar(s).ok_or(ArithmeticError::Overflow)
+    }
+}
+
+// =============================================================================
+// Case 29: TryFrom - a fallible conversion plus an associated `Error` type
+// =============================================================================
+
+/// The error returned when a value is out of range for a [`Scalar`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScalarRangeError(TryFromIntError);
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[ScalarRangeError][Display]fmt().`
+/// - verus-analyzer: `ScalarRangeError#Display#fmt().`
+impl Display for ScalarRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "scalar out of range: {}", self.0)
+    }
+}
+
+impl std::error::Error for ScalarRangeError {}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`TryFrom<i64>`]try_from().`
+/// - verus-analyzer: `Scalar#TryFrom#try_from().`
+///
+/// Expected symbols for the associated `Error` type:
+/// - rust-analyzer: `impl#[Scalar][`TryFrom<i64>`]Error#`
+/// - verus-analyzer: `Scalar#TryFrom#[Error]`
+impl TryFrom<i64> for Scalar {
+    type Error = ScalarRangeError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        i32::try_from(value).map(Scalar).map_err(ScalarRangeError)
+    }
+}
+
+// =============================================================================
+// Case 30: Neg for Point - owned vs reference Self on a second type
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][Neg]neg().`
+/// - verus-analyzer: `Point#Neg#neg().`
+///
+/// Panics on overflow, like the `Scalar` impls in Cases 1 and 2.
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        -&self
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`&Point`][Neg]neg().`
+/// - verus-analyzer: `Neg#neg().`  <-- DUPLICATE! Same as Case 2!
+///
+/// With the `&Point` Self type dropped, nothing distinguishes this impl from
+/// `impl Neg for &Scalar`.
+impl Neg for &Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point((-Scalar(self.0)).0, (-Scalar(self.1)).0)
+    }
+}
+
+// =============================================================================
+// Case 31: Compound assignment - methods taking `&mut self`
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`AddAssign<Self>`]add_assign().`
+/// - verus-analyzer: `Scalar#AddAssign#add_assign().`
+///
+/// The `&mut self` receiver is not part of the symbol in either tool, so it
+/// does not matter that the operand is taken by value in Case 5's `add`.
+///
+/// Panics on overflow.
+impl AddAssign for Scalar {
+    fn add_assign(&mut self, rhs: Scalar) {
+        self.0 = self
+            .0
+            .checked_add(rhs.0)
+            .expect("attempt to add with overflow");
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`MulAssign<Scalar>`]mul_assign().`
+/// - verus-analyzer: `Scalar#MulAssign#mul_assign().`
+///
+/// Unlike `AddAssign` above, the `Rhs` argument is written out, so
+/// rust-analyzer shows `Scalar` rather than `Self`.
+///
+/// Panics on overflow; use [`Scalar::checked_mul`] to handle it.
+impl MulAssign<Scalar> for Scalar {
+    fn mul_assign(&mut self, rhs: Scalar) {
+        *self = self
+            .checked_mul(rhs)
+            .expect("attempt to multiply with overflow");
+    }
+}
+
+// =============================================================================
+// Case 32: Sum/Product - trait methods generic over an iterator
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`Sum<Self>`]sum().`
+/// - verus-analyzer: `Scalar#Sum#sum().`
+///
+/// As with `hash` in Case 27, the `I: Iterator` parameter gets a nested
+/// descriptor rather than changing the method symbol:
+/// `impl#[Scalar][`Sum<Self>`]sum().[I]` and `Scalar#Sum#sum().[I]`.
+impl Sum for Scalar {
+    fn sum<I: Iterator<Item = Scalar>>(iter: I) -> Scalar {
+        iter.fold(Scalar::ZERO, |acc, s| acc + s)
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`Product<Self>`]product().`
+/// - verus-analyzer: `Scalar#Product#product().`
+///
+/// The iterator parameter is `impl#[Scalar][`Product<Self>`]product().[I]` and
+/// `Scalar#Product#product().[I]`. Panics on overflow.
+impl Product for Scalar {
+    fn product<I: Iterator<Item = Scalar>>(iter: I) -> Scalar {
+        iter.fold(Scalar(1), |acc, s| {
+            acc.checked_mul(s)
+                .expect("attempt to multiply with overflow")
+        })
+    }
+}
+
+// =============================================================================
+// Case 33: Inherent associated consts
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar]ZERO.`
+/// - verus-analyzer: `Scalar#ZERO.`
+///
+/// Like the trait consts in Case 11, these end in `.`; with no trait to name,
+/// they follow the inherent method form from Case 7.
+impl Scalar {
+    /// The additive identity.
+    pub const ZERO: Scalar = Scalar(0);
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point]ORIGIN.`
+/// - verus-analyzer: `Point#ORIGIN.`
+impl Point {
+    /// The point `(0, 0)`.
+    pub const ORIGIN: Point = Point(0, 0);
+}
+
+// =============================================================================
+// Case 34: PhantomData - a generic parameter that only appears in a marker
+// =============================================================================
+
+/// An `i32` tagged with a zero-sized marker type.
+#[derive(Clone, Copy, Debug)]
+pub struct Tagged<T> {
+    pub value: i32,
+    _marker: PhantomData<T>,
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`Tagged<T>`]new().`
+/// - verus-analyzer: `Tagged#new().`
+impl<T> Tagged<T> {
+    /// Wraps `value`, tagged with `T`.
+    pub fn new(value: i32) -> Tagged<T> {
+        Tagged {
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`Tagged<T>`][Neg]neg().`
+/// - verus-analyzer: `Tagged#Neg#neg().`  <-- The marker parameter is lost!
+///
+/// Negating a `Tagged<TypeA>` and a `Tagged<TypeB>` both resolve to this one
+/// impl, so there is no collision here. Separate impls for `Tagged<TypeA>` and
+/// `Tagged<TypeB>` would be `impl#[`Tagged<TypeA>`][Neg]neg().` and
+/// `impl#[`Tagged<TypeB>`][Neg]neg().` in rust-analyzer, but would both be
+/// `Tagged#Neg#neg().` in verus-analyzer, exactly as in Case 4.
+impl<T> Neg for Tagged<T> {
+    type Output = Tagged<T>;
+
+    fn neg(self) -> Tagged<T> {
+        Tagged::new(-self.value)
+    }
+}
+
+// =============================================================================
+// Case 35: Closure bounds and higher-ranked trait bounds
+// =============================================================================
+
+/// Applies a function to the wrapped value(s).
+///
+/// Expected symbol for the trait's method (both tools): `Apply#apply().`
+pub trait Apply {
+    fn apply<F: Fn(i32) -> i32>(&self, f: F) -> Self;
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][Apply]apply().`
+/// - verus-analyzer: `Scalar#Apply#apply().`
+///
+/// As in Case 22, the `F` parameter gets a nested descriptor,
+/// `impl#[Scalar][Apply]apply().[F]` and `Scalar#Apply#apply().[F]`; its
+/// `Fn(i32) -> i32` bound does not appear anywhere in the symbol.
+impl Apply for Scalar {
+    fn apply<F: Fn(i32) -> i32>(&self, f: F) -> Scalar {
+        Scalar(f(self.0))
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar]apply_ref().`
+/// - verus-analyzer: `Scalar#apply_ref().`
+///
+/// The `for<'a>` binder is part of a bound, not of any path in the symbol, so
+/// it is erased just like the plain `Fn` bound above. `F` is again
+/// `impl#[Scalar]apply_ref().[F]` and `Scalar#apply_ref().[F]`; the
+/// higher-ranked `'a` gets no symbol at all.
+impl Scalar {
+    /// Applies `f` to a reference to the wrapped value.
+    pub fn apply_ref<F>(&self, f: F) -> Scalar
+    where
+        F: for<'a> Fn(&'a i32) -> &'a i32,
+    {
+        Scalar(*f(&self.0))
+    }
+}
+
+// =============================================================================
+// Case 36: Non-Copy Self - a consuming method on a move-only type
+// =============================================================================
+
+/// A multi-limb scalar. Unlike [`Scalar`], it is `Clone` but not `Copy`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BigScalar(pub Vec<i32>);
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[BigScalar][Neg]neg().`
+/// - verus-analyzer: `BigScalar#Neg#neg().`
+///
+/// `neg` moves `self` here, while for `Scalar` it copies, but neither tool
+/// encodes receivers or `Copy`-ness, so the symbol has the same shape as
+/// Case 1.
+impl Neg for BigScalar {
+    type Output = BigScalar;
+
+    fn neg(mut self) -> BigScalar {
+        for limb in &mut self.0 {
+            *limb = -*limb;
+        }
+        self
+    }
+}
+
+// =============================================================================
+// Case 37: Deref - auto-deref method resolution
+// =============================================================================
+
+/// A newtype around [`Scalar`] that derefs to it.
+#[derive(Clone, Copy, Debug)]
+pub struct ScalarWrapper(pub Scalar);
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[ScalarWrapper][Deref]deref().`
+/// - verus-analyzer: `ScalarWrapper#Deref#deref().`
+///
+/// Expected symbols for the associated `Target` type:
+/// - rust-analyzer: `impl#[ScalarWrapper][Deref]Target#`
+/// - verus-analyzer: `ScalarWrapper#Deref#[Target]`
+///
+/// A call like `wrapper.checked_neg()` resolves to `Scalar`'s method, so its
+/// occurrence refers to `impl#[Scalar]checked_neg().` (Case 7), not to any
+/// `ScalarWrapper` symbol. The implicit `deref` call has no occurrence at all.
+impl Deref for ScalarWrapper {
+    type Target = Scalar;
+
+    fn deref(&self) -> &Scalar {
+        &self.0
+    }
+}
+
+// =============================================================================
+// Case 38: Enum Self - trait impls on a non-struct type
+// =============================================================================
+
+/// The sign of a [`Scalar`]. Zero counts as positive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sign {
+    Positive,
+    Negative,
+}
+
+// Both impls are written through `describe_impl!`, so `all_crate_impls()`
+// describes them straight from the impl headers.
+crate::describe_impl! {
+    fn sign_impls;
+
+    /// Expected symbols:
+    /// - rust-analyzer: `impl#[Sign][Neg]neg().`
+    /// - verus-analyzer: `Sign#Neg#neg().`
+    ///
+    /// Neither tool distinguishes enums from structs in the Self type, so this
+    /// follows Case 1 exactly.
+    impl Neg for Sign {
+        type Output = Sign;
+
+        fn neg(self) -> Sign {
+            match self {
+                Sign::Positive => Sign::Negative,
+                Sign::Negative => Sign::Positive,
+            }
+        }
+    }
+
+    /// Expected symbols:
+    /// - rust-analyzer: `impl#[Sign][`From<Scalar>`]from().`
+    /// - verus-analyzer: `Sign#From#from().`
+    impl From<Scalar> for Sign {
+        fn from(s: Scalar) -> Sign {
+            if s.0 < 0 {
+                Sign::Negative
+            } else {
+                Sign::Positive
+            }
+        }
+    }
+}
+
+// =============================================================================
+// Case 39: Primitive operands - `i32` on either side of `Mul`
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`Mul<i32>`]mul().`
+/// - verus-analyzer: `Scalar#Mul#mul().`  <-- Collides with Case 40!
+///
+/// Panics on overflow.
+impl Mul<i32> for Scalar {
+    type Output = Scalar;
+
+    fn mul(self, rhs: i32) -> Scalar {
+        self.checked_mul(Scalar(rhs))
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[i32][`Mul<Scalar>`]mul().`
+/// - verus-analyzer: `Mul#mul().`  <-- DUPLICATE! Same as Case 3!
+///
+/// rust-analyzer names a primitive Self type just like a struct, as in core's
+/// own `impl#[i32][`Mul<Self>`]mul().`. verus-analyzer only names ADTs, so
+/// `i32` is dropped like the references in Case 3.
+impl Mul<Scalar> for i32 {
+    type Output = Scalar;
+
+    fn mul(self, rhs: Scalar) -> Scalar {
+        rhs * self
+    }
+}
+
+// =============================================================================
+// Case 40: Owned Mul - the by-value counterpart of Case 3
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][`Mul<Scalar>`]mul().`
+/// - verus-analyzer: `Point#Mul#mul().`
+///
+/// Unlike `&Point` in Case 3, the owned `Point` is an ADT and is kept, so this
+/// impl does NOT collide with the reference impls under verus-analyzer.
+/// Panics on overflow.
+impl Mul<Scalar> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: Scalar) -> Point {
+        &self * &rhs
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`Mul<Point>`]mul().`
+/// - verus-analyzer: `Scalar#Mul#mul().`  <-- DUPLICATE! Same as Case 39!
+///
+/// This does NOT collide with Case 3's `Mul#mul().` either, but since the
+/// trait arguments are stripped, it shares `Scalar#Mul#mul().` with
+/// `impl Mul<i32> for Scalar`. Keeping the Self type is not enough when one
+/// type implements the same trait twice.
+impl Mul<Point> for Scalar {
+    type Output = Point;
+
+    fn mul(self, rhs: Point) -> Point {
+        &self * &rhs
+    }
+}
+
+// =============================================================================
+// Case 41: Hand-written generic Clone - a bounded generic trait impl
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`Container<T>`][Clone]clone().`
+/// - verus-analyzer: `Container#Clone#clone().`
+///
+/// The `T: Clone` bound does not appear in either symbol; rust-analyzer keeps
+/// the `<T>` parameter, verus-analyzer drops it as in Case 4. This is also the
+/// impl `#[derive(Clone)]` would generate, so deriving instead would give the
+/// same symbols (attributed to the derive rather than to this source).
+impl<T: Clone> Clone for Container<T> {
+    fn clone(&self) -> Container<T> {
+        Container {
+            value: self.value.clone(),
+        }
+    }
+}
+
+// =============================================================================
+// Case 42: From<Point> for Scalar - a non-generic From on Scalar
+// =============================================================================
+
+/// Reduces a point to the sum of its components.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`From<Point>`]from().`
+/// - verus-analyzer: `Scalar#From#from().`  <-- DUPLICATE! Same as Case 15!
+///
+/// The Self type is kept, so this does not collide with the Case 4
+/// `Container#From#from().` impls. But `From<i32> for Scalar` is also
+/// `Scalar#From#from().` once the trait argument is stripped.
+impl From<Point> for Scalar {
+    fn from(p: Point) -> Scalar {
+        Scalar(p.0 + p.1)
+    }
+}
+
+// =============================================================================
+// Case 43: Owned Mul for Scalar - a control for Case 3
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`Mul<Self>`]mul().`
+/// - verus-analyzer: `Scalar#Mul#mul().`  <-- DUPLICATE! Same as Cases 39, 40 and 65!
+///
+/// The owned Self type is kept, so this is distinct from the reference impls'
+/// `Mul#mul().`. It still joins the `Scalar#Mul#mul().` group, since
+/// verus-analyzer cannot tell `Mul<Self>` from `Mul<i32>` or `Mul<Point>`.
+/// Panics on overflow.
+impl Mul for Scalar {
+    type Output = Scalar;
+
+    fn mul(self, rhs: Scalar) -> Scalar {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+// =============================================================================
+// Case 44: A different Output type - can associated types disambiguate?
+// =============================================================================
+
+/// Multiplies out to a bare `i32` rather than a `Scalar` or `Point`.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`&Scalar`][`Mul<Scalar>`]mul().`
+/// - verus-analyzer: `Mul#mul().`  <-- DUPLICATE! Same as Case 3!
+///
+/// Expected symbols for the associated `Output` type:
+/// - rust-analyzer: `impl#[`&Scalar`][`Mul<Scalar>`]Output#`
+/// - verus-analyzer: `Mul#[Output]`
+///
+/// The `Output` alias gets a symbol in both tools, but it is named after the
+/// impl, not after the type it is set to. Under verus-analyzer the Case 3
+/// impls' `Output`s are `Mul#[Output]` too, so associated types collide
+/// exactly like the methods and cannot disambiguate them.
+impl Mul<Scalar> for &Scalar {
+    type Output = i32;
+
+    fn mul(self, rhs: Scalar) -> i32 {
+        (*self * rhs).0
+    }
+}
+
+// =============================================================================
+// Case 45: Smart-pointer Self - `Box<Scalar>` vs `&Scalar`
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`Box<Scalar>`][Neg]neg().`
+/// - verus-analyzer: `Box#Neg#neg().`  <-- Named after the box, not `Scalar`!
+///
+/// `Box` is `#[fundamental]`, which is what makes this impl legal here. Unlike
+/// `&Scalar` in Case 2, `Box<Scalar>` is an ADT, so verus-analyzer does name
+/// the Self type. But like `Container<T>` in Case 4, it strips the generic
+/// arguments, so a `Neg for Box<Point>` impl would collide with this one.
+impl Neg for Box<Scalar> {
+    type Output = Scalar;
+
+    fn neg(self) -> Scalar {
+        -*self
+    }
+}
+
+// =============================================================================
+// Case 46: Default - associated functions without a receiver
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][Default]default().`
+/// - verus-analyzer: `Scalar#Default#default().`
+///
+/// `default` takes no `self`, but SCIP uses the same `().` method suffix for
+/// associated functions and methods. The difference only shows in the
+/// `SymbolInformation` kind, `StaticMethod` rather than `Method` (see
+/// [`scip::SYMBOL_KIND_STATIC_METHOD`]).
+impl Default for Scalar {
+    fn default() -> Scalar {
+        Scalar::ZERO
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][Default]default().`
+/// - verus-analyzer: `Point#Default#default().`
+impl Default for Point {
+    fn default() -> Point {
+        Point::ORIGIN
+    }
+}
+
+// =============================================================================
+// Case 47: Div/Rem - operators with a division-by-zero panic
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar]try_div().`, `impl#[Scalar]try_rem().`
+/// - verus-analyzer: `Scalar#try_div().`, `Scalar#try_rem().`
+impl Scalar {
+    /// Divides two scalars, returning [`ArithmeticError::DivByZero`] for a zero
+    /// divisor and [`ArithmeticError::Overflow`] for `i32::MIN / -1`.
+    pub fn try_div(self, rhs: Scalar) -> Result<Scalar, ArithmeticError> {
+        if rhs.0 == 0 {
+            return Err(ArithmeticError::DivByZero);
+        }
+        self.0
+            .checked_div(rhs.0)
+            .map(Scalar)
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Takes the remainder of dividing two scalars, with the same errors as
+    /// [`Scalar::try_div`].
+    pub fn try_rem(self, rhs: Scalar) -> Result<Scalar, ArithmeticError> {
+        if rhs.0 == 0 {
+            return Err(ArithmeticError::DivByZero);
+        }
+        self.0
+            .checked_rem(rhs.0)
+            .map(Scalar)
+            .ok_or(ArithmeticError::Overflow)
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`Div<Self>`]div().`
+/// - verus-analyzer: `Scalar#Div#div().`
+///
+/// Panics with "division by zero" for a zero divisor, and on overflow; use
+/// [`Scalar::try_div`] to handle either.
+impl Div for Scalar {
+    type Output = Scalar;
+
+    fn div(self, rhs: Scalar) -> Scalar {
+        self.try_div(rhs).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`Rem<Self>`]rem().`
+/// - verus-analyzer: `Scalar#Rem#rem().`
+///
+/// Panics like `div` above; use [`Scalar::try_rem`] to handle errors.
+impl Rem for Scalar {
+    type Output = Scalar;
+
+    fn rem(self, rhs: Scalar) -> Scalar {
+        self.try_rem(rhs).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+// =============================================================================
+// Case 48: PartialEq<i32> - comparing against the inner type
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`PartialEq<i32>`]eq().`
+/// - verus-analyzer: `Scalar#PartialEq#eq().` <-- Collides with Case 25!
+///
+/// Only the `Rhs` argument tells this apart from `PartialEq for Scalar`.
+impl PartialEq<i32> for Scalar {
+    fn eq(&self, other: &i32) -> bool {
+        self.0 == *other
+    }
+}
+
+// =============================================================================
+// Case 49: From on a concrete instantiation of the generic Scalar<T>
+// =============================================================================
+
+/// Widen an i32 into a 64-bit scalar.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`Scalar<i64>`][`From<i32>`]from().`
+/// - verus-analyzer: `Scalar#From#from().`  <-- Collides with Cases 15 and 42!
+///
+/// Case 15's `From<i32> for Scalar` is the `Scalar<i32>` counterpart of this
+/// impl. verus-analyzer strips the generic arguments of the Self type just as
+/// it strips those of `Container<TypeA>` in Case 4, so the two instantiations
+/// share a symbol.
+impl From<i32> for Scalar<i64> {
+    fn from(value: i32) -> Self {
+        Scalar(i64::from(value))
+    }
+}
+
+// =============================================================================
+// Case 50: Trait objects - `dyn Shape` in the Self type
+// =============================================================================
+
+/// A figure with an area.
+///
+/// Expected symbol for the trait's method (both tools): `Shape#area().`
+pub trait Shape {
+    fn area(&self) -> i32;
+}
+
+/// The area of the rectangle spanned by the origin and the point.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][Shape]area().`
+/// - verus-analyzer: `Point#Shape#area().`
+impl Shape for Point {
+    fn area(&self) -> i32 {
+        (self.0 * self.1).abs()
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`Box<dyn Shape>`][Shape]area().`
+/// - verus-analyzer: `Box#Shape#area().`
+///
+/// rust-analyzer keeps the trait object in the Self type, where the space after
+/// `dyn` calls for quoting. verus-analyzer strips the generic arguments of
+/// `Box` as in Case 45, so the trait object disappears from its symbol.
+impl Shape for Box<dyn Shape> {
+    fn area(&self) -> i32 {
+        (**self).area()
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`dyn Shape`]is_degenerate().`
+/// - verus-analyzer: `is_degenerate().`
+///
+/// With the trait object itself as the Self type, verus-analyzer drops the
+/// Self type entirely, like it does for references in Case 2.
+impl dyn Shape {
+    pub fn is_degenerate(&self) -> bool {
+        self.area() == 0
+    }
+}
+
+// =============================================================================
+// Case 51: Iterator - a custom iterator yielding Scalars
+// =============================================================================
+
+/// The scalars from `current` up to, but not including, `end`.
+#[derive(Clone, Debug)]
+pub struct ScalarRange {
+    current: i32,
+    end: i32,
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[ScalarRange]new().`
+/// - verus-analyzer: `ScalarRange#new().`
+impl ScalarRange {
+    pub fn new(start: i32, end: i32) -> ScalarRange {
+        ScalarRange {
+            current: start,
+            end,
+        }
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[ScalarRange][Iterator]next().`
+/// - verus-analyzer: `ScalarRange#Iterator#next().`
+///
+/// Expected symbols for the associated `Item` type:
+/// - rust-analyzer: `impl#[ScalarRange][Iterator]Item#`
+/// - verus-analyzer: `ScalarRange#Iterator#[Item]`
+///
+/// The provided methods, like `collect`, are not redefined here, so calls to
+/// them reference `Iterator`'s own symbols in `core`.
+impl Iterator for ScalarRange {
+    type Item = Scalar;
+
+    fn next(&mut self) -> Option<Scalar> {
+        if self.current >= self.end {
+            return None;
+        }
+        let item = Scalar(self.current);
+        self.current += 1;
+        Some(item)
+    }
+}
+
+// =============================================================================
+// Case 52: Raw-pointer Self - `*const Scalar`
+// =============================================================================
+
+/// Negation through a raw pointer.
+///
+/// `impl Neg for *const Scalar` would break the orphan rules, since unlike
+/// references raw pointers are not fundamental types, hence the local trait.
+///
+/// Expected symbol for the trait's method (both tools): `RawNeg#raw_neg().`
+pub trait RawNeg {
+    /// Negates the pointee.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be non-null, aligned and point to an initialized value.
+    unsafe fn raw_neg(self) -> Scalar;
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`*const Scalar`][RawNeg]raw_neg().`
+/// - verus-analyzer: `RawNeg#raw_neg().`  <-- Missing the `*const Scalar` Self type!
+///
+/// This is Case 2 with a pointer for the reference: rust-analyzer quotes the
+/// Self type because of the space after `*const`, and verus-analyzer drops it
+/// as it is not an ADT. `unsafe` is not part of either symbol.
+impl RawNeg for *const Scalar {
+    unsafe fn raw_neg(self) -> Scalar {
+        // SAFETY: the caller guarantees that `self` points to a valid Scalar.
+        -unsafe { *self }
+    }
+}
+
+// =============================================================================
+// Case 53: Overflow modes - one trait, one wrapper per semantics
+// =============================================================================
+
+/// Multiplies by a scalar with the wrapper's overflow semantics.
+///
+/// Expected symbol for the trait's method (both tools): `ScalarArith#combine().`
+pub trait ScalarArith {
+    fn combine(self, rhs: Scalar) -> Scalar;
+}
+
+/// A scalar whose multiplication wraps around on overflow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Wrapping(pub Scalar);
+
+/// A scalar whose multiplication clamps to `i32::MIN`/`i32::MAX` on overflow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Saturating(pub Scalar);
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Wrapping][ScalarArith]combine().`
+/// - verus-analyzer: `Wrapping#ScalarArith#combine().`
+impl ScalarArith for Wrapping {
+    fn combine(self, rhs: Scalar) -> Scalar {
+        Scalar(self.0 .0.wrapping_mul(rhs.0))
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Saturating][ScalarArith]combine().`
+/// - verus-analyzer: `Saturating#ScalarArith#combine().`
+impl ScalarArith for Saturating {
+    fn combine(self, rhs: Scalar) -> Scalar {
+        Scalar(self.0 .0.saturating_mul(rhs.0))
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Wrapping][`Mul<Self>`]mul().`
+/// - verus-analyzer: `Wrapping#Mul#mul().`
+impl Mul for Wrapping {
+    type Output = Wrapping;
+
+    fn mul(self, rhs: Wrapping) -> Wrapping {
+        Wrapping(self.combine(rhs.0))
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Saturating][`Mul<Self>`]mul().`
+/// - verus-analyzer: `Saturating#Mul#mul().`
+///
+/// The wrappers are distinct owned ADTs, so none of their symbols collide.
+impl Mul for Saturating {
+    type Output = Saturating;
+
+    fn mul(self, rhs: Saturating) -> Saturating {
+        Saturating(self.combine(rhs.0))
+    }
+}
+
+// =============================================================================
+// Case 54: Homogeneous reference Mul - `&Scalar * &Scalar`
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`&Scalar`][`Mul<&Scalar>`]mul().`
+/// - verus-analyzer: `Mul#mul().`  <-- DUPLICATE! Same as Cases 3, 39 and 44!
+///
+/// The reference Self type is dropped as in Case 2, and the trait argument is
+/// stripped as in Case 3. The two losses do not compound into anything new:
+/// the result is the same `Mul#mul().` as every other non-ADT `Mul` impl, and
+/// only rust-analyzer keeps the `&Scalar` argument. Panics on overflow.
+impl Mul<&Scalar> for &Scalar {
+    type Output = Scalar;
+
+    fn mul(self, rhs: &Scalar) -> Scalar {
+        self.checked_mul(*rhs)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+// =============================================================================
+// Case 55: Case 4 with a different source type
+// =============================================================================
+
+/// Convert from a Point reference to Container<TypeA>.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`Container<TypeA>`][`From<&Point>`]from().`
+/// - verus-analyzer: `Container#From#from().`  <-- DUPLICATE! Same as Cases 4, 10 and 21!
+///
+/// The Self type is exactly that of the first Case 4 impl; only the source type
+/// differs. verus-analyzer strips `<&Point>` as readily as `<&Scalar>`, so the
+/// collision is driven by the lost generic arguments alone, whichever side
+/// they are on.
+impl From<&Point> for Container<TypeA> {
+    fn from(_p: &Point) -> Self {
+        Container { value: TypeA }
+    }
+}
+
+// =============================================================================
+// Case 56: AsRef and AsMut - borrowing the inner value
+// =============================================================================
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`AsRef<i32>`]as_ref().`
+/// - verus-analyzer: `Scalar#AsRef#as_ref().`
+///
+/// Return types never appear in method symbols, so returning `&i32` makes no
+/// difference, just as for `index` in Case 18 and `deref` in Case 37.
+impl AsRef<i32> for Scalar {
+    fn as_ref(&self) -> &i32 {
+        &self.0
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`AsMut<i32>`]as_mut().`
+/// - verus-analyzer: `Scalar#AsMut#as_mut().`
+impl AsMut<i32> for Scalar {
+    fn as_mut(&mut self) -> &mut i32 {
+        &mut self.0
+    }
+}
+
+// =============================================================================
+// Case 57: Nested module - a `geometry/` namespace before the impl
+// =============================================================================
+
+/// Types defined outside the crate root, whose impl symbols are qualified by
+/// the module path.
+pub mod geometry {
+    use std::ops::Neg;
+
+    /// A two-dimensional vector.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Vec2(pub i32, pub i32);
+
+    /// Expected symbols:
+    /// - rust-analyzer: `geometry/impl#[Vec2][Neg]neg().`
+    /// - verus-analyzer: `geometry/Vec2#Neg#neg().`
+    ///
+    /// Each enclosing module adds a namespace descriptor such as `geometry/`
+    /// in front of the impl's descriptors, so this does not clash with an
+    /// identically named `Vec2` at the crate root. Panics on overflow.
+    impl Neg for Vec2 {
+        type Output = Vec2;
+
+        fn neg(self) -> Vec2 {
+            self.0
+                .checked_neg()
+                .zip(self.1.checked_neg())
+                .map(|(x, y)| Vec2(x, y))
+                .expect("attempt to negate with overflow")
+        }
+    }
+}
+
+// =============================================================================
+// Case 58: Binary and Octal - other formatting traits with a `fmt` method
+// =============================================================================
+
+/// Formats the inner value in binary, so `format!("{:b}", Scalar(5))` is `101`.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][Binary]fmt().`
+/// - verus-analyzer: `Scalar#Binary#fmt().`
+///
+/// The method name and owned Self type match Case 16's Display impl, but the
+/// trait name is kept in both formats, so `Scalar#Binary#fmt().` and
+/// `Scalar#Display#fmt().` do not collide. verus-analyzer only loses trait
+/// *arguments*, and none of the formatting traits take any.
+impl Binary for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Binary::fmt(&self.0, f)
+    }
+}
+
+/// Formats the inner value in octal, so `format!("{:o}", Scalar(8))` is `10`.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][Octal]fmt().`
+/// - verus-analyzer: `Scalar#Octal#fmt().`
+impl Octal for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Octal::fmt(&self.0, f)
+    }
+}
+
+// =============================================================================
+// Case 59: Mixed reference/owned Mul - `&Point * Scalar`
+// =============================================================================
+
+/// Multiply a point reference by an owned scalar.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[`&Point`][`Mul<Scalar>`]mul().`
+/// - verus-analyzer: `Mul#mul().`  <-- DUPLICATE! Same as Cases 3, 39, 44 and 54!
+///
+/// Compared with Case 3's `Mul<&Scalar> for &Point`, only the trait argument
+/// lost its `&`. verus-analyzer drops the `&Point` Self type all the same, so
+/// whether the argument is a reference makes no difference to its symbol.
+impl Mul<Scalar> for &Point {
+    type Output = Point;
+
+    fn mul(self, rhs: Scalar) -> Point {
+        // `self * rhs` would call this impl again.
+        Mul::<&Scalar>::mul(self, &rhs)
+    }
+}
+
+// =============================================================================
+// Case 60: PartialOrd and Ord for a multi-field type
+// =============================================================================
+
+/// Orders points lexicographically: by x, then by y.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][`PartialOrd<Self>`]partial_cmp().`
+/// - verus-analyzer: `Point#PartialOrd#partial_cmp().`
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Point) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][Ord]cmp().`
+/// - verus-analyzer: `Point#Ord#cmp().`
+///
+/// Structurally identical to Case 26's `Scalar` symbols in both tools: only
+/// the Self type differs. Comparing two fields instead of one is invisible to
+/// the symbol.
+impl Ord for Point {
+    fn cmp(&self, other: &Point) -> Ordering {
+        self.0.cmp(&other.0).then(self.1.cmp(&other.1))
+    }
+}
+
+// =============================================================================
+// Case 61: Add for Point - completing Point's componentwise arithmetic
+// =============================================================================
+
+/// Add two points componentwise.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Point][`Add<Self>`]add().`
+/// - verus-analyzer: `Point#Add#add().`
+///
+/// The counterpart of Case 6's `impl#[Point][`Sub<Self>`]sub().`. Panics on
+/// overflow, like `neg` and `sub` on `Point`.
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        self.0
+            .checked_add(rhs.0)
+            .zip(self.1.checked_add(rhs.1))
+            .map(|(x, y)| Point(x, y))
+            .expect("attempt to add with overflow")
+    }
+}
+
+// =============================================================================
+// Case 62: A return type different from Self
+// =============================================================================
+
+/// Converts a value into a point.
+///
+/// Expected symbol for the trait's method (both tools): `ToPoint#to_point().`
+pub trait ToPoint {
+    fn to_point(self) -> Point;
+}
+
+/// Puts the scalar on the diagonal: `Scalar(5).to_point()` is `Point(5, 5)`.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][ToPoint]to_point().`
+/// - verus-analyzer: `Scalar#ToPoint#to_point().`
+///
+/// The `Point` return type appears in neither symbol: SCIP method descriptors
+/// carry no signature, only an optional disambiguator. The same holds for
+/// associated `Output` types, which get symbols of their own (Case 44) but do
+/// not change the method's.
+impl ToPoint for Scalar {
+    fn to_point(self) -> Point {
+        Point(self.0, self.0)
+    }
+}
+
+// =============================================================================
+// Case 63: Neg for Container<T> - a generic Self type
+// =============================================================================
+
+crate::describe_impl! {
+    fn container_neg_impls;
+
+    /// Negates the contained value.
+    ///
+    /// Expected symbols:
+    /// - rust-analyzer: `impl#[`Container<T>`][Neg]neg().`
+    /// - verus-analyzer: `Container#Neg#neg().`
+    ///
+    /// Symbols name impls, not instantiations, so `Container<Scalar>` and
+    /// `Container<i32>` negate through this one symbol in both tools and there is
+    /// nothing to collide. Case 4 collides because it writes one impl per
+    /// concrete `Container<TypeA>`/`Container<TypeB>`; a second concrete
+    /// `Neg for Container<i32>` next to a `Neg for Container<Scalar>` would
+    /// collide the same way, but cannot coexist with this blanket impl.
+    impl<T: Neg<Output = T>> Neg for Container<T> {
+        type Output = Container<T>;
+
+        fn neg(self) -> Container<T> {
+            Container { value: -self.value }
+        }
+    }
+}
+
+// =============================================================================
+// Case 64: TryFrom for Sign - rejecting zero
+// =============================================================================
+
+/// The error returned when converting zero, which has no sign, into a [`Sign`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignError;
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[SignError][Display]fmt().`
+/// - verus-analyzer: `SignError#Display#fmt().`
+impl Display for SignError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "zero has no sign")
+    }
+}
+
+impl std::error::Error for SignError {}
+
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Sign][`TryFrom<&Scalar>`]try_from().`
+/// - verus-analyzer: `Sign#TryFrom#try_from().`
+///
+/// Expected symbols for the associated `Error` type:
+/// - rust-analyzer: `impl#[Sign][`TryFrom<&Scalar>`]Error#`
+/// - verus-analyzer: `Sign#TryFrom#[Error]`
+///
+/// This converts from `&Scalar` because Case 38's `From<Scalar> for Sign`
+/// already provides `TryFrom<Scalar>` through core's blanket impl, whose
+/// symbols live in core rather than in this crate.
+impl TryFrom<&Scalar> for Sign {
+    type Error = SignError;
+
+    fn try_from(s: &Scalar) -> Result<Sign, SignError> {
+        match s.0.cmp(&0) {
+            Ordering::Greater => Ok(Sign::Positive),
+            Ordering::Less => Ok(Sign::Negative),
+            Ordering::Equal => Err(SignError),
+        }
+    }
+}
+
+// =============================================================================
+// Case 65: Mul with generic trait arguments - Cases 3 and 4 combined
+// =============================================================================
+
+/// Scale a `Container<TypeA>`. The markers carry no magnitude, so the product
+/// is the container itself.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`Mul<Container<TypeA>>`]mul().`
+/// - verus-analyzer: `Scalar#Mul#mul().`  <-- DUPLICATE! Same as Cases 39, 40 and 43!
+impl Mul<Container<TypeA>> for Scalar {
+    type Output = Container<TypeA>;
+
+    fn mul(self, rhs: Container<TypeA>) -> Container<TypeA> {
+        rhs
+    }
+}
+
+/// Scale a `Container<TypeB>`.
+///
+/// Expected symbols:
+/// - rust-analyzer: `impl#[Scalar][`Mul<Container<TypeB>>`]mul().`
+/// - verus-analyzer: `Scalar#Mul#mul().`  <-- DUPLICATE! Same as above!
+///
+/// As in Case 4, the impls differ only in `TypeA` vs `TypeB`, and
+/// verus-analyzer drops the whole trait argument list. Here the generic type is
+/// a `Mul` argument rather than the Self type of a `From` impl, but the
+/// outcome is the same: both join the `Scalar#Mul#mul().` group.
+impl Mul<Container<TypeB>> for Scalar {
+    type Output = Container<TypeB>;
+
+    fn mul(self, rhs: Container<TypeB>) -> Container<TypeB> {
+        rhs
+    }
+}
+
+// =============================================================================
+// Catalog of the impls above
+// =============================================================================
+
+/// How many impl methods each Case adds to [`all_crate_impls`], as
+/// `(case, count)`, with one row per Case in the crate docs.
+///
+/// Update this alongside the catalog when adding a Case, so the test comparing
+/// the two catches an impl that was written but not registered, or vice versa.
+const METHODS_PER_CASE: &[(u32, usize)] = &[
+    (1, 1),
+    (2, 1),
+    (3, 2),
+    (4, 2),
+    (5, 3),
+    (6, 3),
+    (7, 3),
+    (8, 2),
+    (9, 1),
+    (10, 1),
+    (11, 0), // Associated consts only
+    (12, 1),
+    (13, 1),
+    (14, 1),
+    (15, 2),
+    (16, 2),
+    (17, 5),
+    (18, 2),
+    (19, 2),
+    (20, 2),
+    (21, 1),
+    (22, 1),
+    (23, 1),
+    (24, 1),
+    (25, 2),
+    (26, 2),
+    (27, 2),
+    (28, 3),
+    (29, 2),
+    (30, 2),
+    (31, 2),
+    (32, 2),
+    (33, 0), // Associated consts only
+    (34, 2),
+    (35, 2),
+    (36, 1),
+    (37, 1),
+    (38, 2),
+    (39, 2),
+    (40, 2),
+    (41, 1),
+    (42, 1),
+    (43, 1),
+    (44, 1),
+    (45, 1),
+    (46, 2),
+    (47, 4),
+    (48, 1),
+    (49, 1),
+    (50, 3),
+    (51, 2),
+    (52, 1),
+    (53, 4),
+    (54, 1),
+    (55, 1),
+    (56, 2),
+    (57, 1),
+    (58, 2),
+    (59, 1),
+    (60, 2),
+    (61, 1),
+    (62, 1),
+    (63, 1),
+    (64, 2),
+    (65, 2),
+];
+
+/// The number of impl methods the crate intends [`all_crate_impls`] to list.
+pub fn expected_symbol_count() -> usize {
+    METHODS_PER_CASE.iter().map(|&(_, count)| count).sum()
+}
+
+/// Describes the impls written through [`describe_impl!`], in source order.
+pub fn registered_impls() -> Vec<ImplDescriptor> {
+    let mut impls = sign_impls();
+    impls.extend(container_neg_impls());
+    impls
+}
+
+/// Describes every impl method in this crate, in source order.
+///
+/// Each descriptor's `source_line` is the line of its impl header in this file.
+/// The impls written through [`describe_impl!`] are spliced in from their
+/// registries rather than listed again.
+pub fn all_crate_impls() -> Vec<ImplDescriptor> {
+    let mut impls = vec![
+        // Case 1
+        ImplDescriptor::new("Scalar", Some("Neg"), &[], "neg"),
+        // Case 2
+        ImplDescriptor::new("&Scalar", Some("Neg"), &[], "neg"),
+        // Case 3
+        ImplDescriptor::new("&Point", Some("Mul"), &["&Scalar"], "mul"),
+        ImplDescriptor::new("&Scalar", Some("Mul"), &["&Point"], "mul"),
+        // Case 4
+        ImplDescriptor::new("Container<TypeA>", Some("From"), &["&Scalar"], "from"),
+        ImplDescriptor::new("Container<TypeB>", Some("From"), &["&Scalar"], "from"),
+        // Case 5
+        ImplDescriptor::new("Scalar<T>", Some("Add"), &["Self"], "add"),
+        ImplDescriptor::new("&Scalar<T>", Some("Add"), &["&Scalar<T>"], "add"),
+        ImplDescriptor::new("&Scalar<T>", Some("Add"), &["Scalar<T>"], "add"),
+        // Case 6
+        ImplDescriptor::new("Scalar<T>", Some("Sub"), &["Self"], "sub"),
+        ImplDescriptor::new("Point", Some("Sub"), &["Self"], "sub"),
+        ImplDescriptor::new("&Point", Some("Sub"), &["&Scalar"], "sub"),
+        // Case 7
+        ImplDescriptor::inherent("Scalar", "checked_neg"),
+        ImplDescriptor::inherent("Scalar", "checked_mul"),
+        ImplDescriptor::inherent("Point", "checked_mul_scalar"),
+        // Case 8
+        ImplDescriptor::new("Vector<N>", Some("Neg"), &[], "neg"),
+        ImplDescriptor::new("Vector<N>", Some("Add"), &["Self"], "add"),
+        // Case 9
+        ImplDescriptor::new("Ref<'a>", Some("Neg"), &[], "neg"),
+        // Case 10
+        ImplDescriptor::new("Container<T>", Some("From"), &["Scalar"], "from"),
+        // Case 12
+        ImplDescriptor::new("T", Some("Double"), &[], "double"),
+        // Case 13
+        ImplDescriptor::new("Scalar", Some("Magnitude"), &[], "magnitude"),
+        // Case 14
+        ImplDescriptor::new("Scalar", Some("Describe"), &[], "label"),
+        // Case 15
+        ImplDescriptor::new("Scalar", Some("From"), &["i32"], "from"),
+        ImplDescriptor::new("Point", Some("From"), &["(i32, i32)"], "from"),
+        // Case 16
+        ImplDescriptor::new("Scalar", Some("Display"), &[], "fmt"),
+        ImplDescriptor::new("Point", Some("Display"), &[], "fmt"),
+        // Case 17
+        ImplDescriptor::inherent("ParseScalarError", "kind"),
+        ImplDescriptor::new("ParseScalarError", Some("Display"), &[], "fmt"),
+        ImplDescriptor::new("Scalar", Some("FromStr"), &[], "from_str"),
+        ImplDescriptor::new("ParsePointError", Some("Display"), &[], "fmt"),
+        ImplDescriptor::new("Point", Some("FromStr"), &[], "from_str"),
+        // Case 18
+        ImplDescriptor::new("Point", Some("Index"), &["usize"], "index"),
+        ImplDescriptor::new("Point", Some("IndexMut"), &["usize"], "index_mut"),
+        // Case 19
+        ImplDescriptor::new("Point", Some("IntoIterator"), &[], "into_iter"),
+        ImplDescriptor::new("&Point", Some("IntoIterator"), &[], "into_iter"),
+        // Case 20
+        ImplDescriptor::new("Pair<TypeA, TypeB>", Some("From"), &["&Scalar"], "from"),
+        ImplDescriptor::new("Pair<TypeB, TypeA>", Some("From"), &["&Scalar"], "from"),
+        // Case 21
+        ImplDescriptor::new(
+            "Container<Container<TypeA>>",
+            Some("From"),
+            &["&Scalar"],
+            "from",
+        ),
+        // Case 22
+        ImplDescriptor::inherent("Scalar", "convert"),
+        // Case 23
+        ImplDescriptor::new("(Scalar, Scalar)", Some("ElementwiseNeg"), &[], "neg"),
+        // Case 24
+        ImplDescriptor::new("[Scalar; 2]", Some("ElementwiseNeg"), &[], "neg"),
+        // Case 25
+        ImplDescriptor::new("Scalar", Some("PartialEq"), &["Self"], "eq"),
+        ImplDescriptor::new("Point", Some("PartialEq"), &["Self"], "eq"),
+        // Case 26
+        ImplDescriptor::new("Scalar", Some("PartialOrd"), &["Self"], "partial_cmp"),
+        ImplDescriptor::new("Scalar", Some("Ord"), &[], "cmp"),
+        // Case 27
+        ImplDescriptor::new("Scalar", Some("Hash"), &[], "hash"),
+        ImplDescriptor::new("Point", Some("Hash"), &[], "hash"),
+        // Case 28
+        ImplDescriptor::new("ArithmeticError", Some("Display"), &[], "fmt"),
+        ImplDescriptor::inherent("Scalar", "try_mul"),
+        ImplDescriptor::inherent("Point", "try_scale"),
+        // Case 29
+        ImplDescriptor::new("ScalarRangeError", Some("Display"), &[], "fmt"),
+        ImplDescriptor::new("Scalar", Some("TryFrom"), &["i64"], "try_from"),
+        // Case 30
+        ImplDescriptor::new("Point", Some("Neg"), &[], "neg"),
+        ImplDescriptor::new("&Point", Some("Neg"), &[], "neg"),
+        // Case 31
+        ImplDescriptor::new("Scalar", Some("AddAssign"), &["Self"], "add_assign"),
+        ImplDescriptor::new("Scalar", Some("MulAssign"), &["Scalar"], "mul_assign"),
+        // Case 32
+        ImplDescriptor::new("Scalar", Some("Sum"), &["Self"], "sum"),
+        ImplDescriptor::new("Scalar", Some("Product"), &["Self"], "product"),
+        // Case 34
+        ImplDescriptor::inherent("Tagged<T>", "new"),
+        ImplDescriptor::new("Tagged<T>", Some("Neg"), &[], "neg"),
+        // Case 35
+        ImplDescriptor::new("Scalar", Some("Apply"), &[], "apply"),
+        ImplDescriptor::inherent("Scalar", "apply_ref"),
+        // Case 36
+        ImplDescriptor::new("BigScalar", Some("Neg"), &[], "neg"),
+        // Case 37
+        ImplDescriptor::new("ScalarWrapper", Some("Deref"), &[], "deref"),
+    ];
+    // Case 38
+    impls.extend(sign_impls());
+    impls.extend([
+        // Case 39
+        ImplDescriptor::new("Scalar", Some("Mul"), &["i32"], "mul"),
+        ImplDescriptor::new("i32", Some("Mul"), &["Scalar"], "mul"),
+        // Case 40
+        ImplDescriptor::new("Point", Some("Mul"), &["Scalar"], "mul"),
+        ImplDescriptor::new("Scalar", Some("Mul"), &["Point"], "mul"),
+        // Case 41
+        ImplDescriptor::new("Container<T>", Some("Clone"), &[], "clone"),
+        // Case 42
+        ImplDescriptor::new("Scalar", Some("From"), &["Point"], "from"),
+        // Case 43
+        ImplDescriptor::new("Scalar", Some("Mul"), &["Self"], "mul"),
+        // Case 44
+        ImplDescriptor::new("&Scalar", Some("Mul"), &["Scalar"], "mul"),
+        // Case 45
+        ImplDescriptor::new("Box<Scalar>", Some("Neg"), &[], "neg"),
+        // Case 46
+        ImplDescriptor::new("Scalar", Some("Default"), &[], "default"),
+        ImplDescriptor::new("Point", Some("Default"), &[], "default"),
+        // Case 47
+        ImplDescriptor::inherent("Scalar", "try_div"),
+        ImplDescriptor::inherent("Scalar", "try_rem"),
+        ImplDescriptor::new("Scalar", Some("Div"), &["Self"], "div"),
+        ImplDescriptor::new("Scalar", Some("Rem"), &["Self"], "rem"),
+        // Case 48
+        ImplDescriptor::new("Scalar", Some("PartialEq"), &["i32"], "eq"),
+        // Case 49
+        ImplDescriptor::new("Scalar<i64>", Some("From"), &["i32"], "from"),
+        // Case 50
+        ImplDescriptor::new("Point", Some("Shape"), &[], "area"),
+        ImplDescriptor::new("Box<dyn Shape>", Some("Shape"), &[], "area"),
+        ImplDescriptor::inherent("dyn Shape", "is_degenerate"),
+        // Case 51
+        ImplDescriptor::inherent("ScalarRange", "new"),
+        ImplDescriptor::new("ScalarRange", Some("Iterator"), &[], "next"),
+        // Case 52
+        ImplDescriptor::new("*const Scalar", Some("RawNeg"), &[], "raw_neg"),
+        // Case 53
+        ImplDescriptor::new("Wrapping", Some("ScalarArith"), &[], "combine"),
+        ImplDescriptor::new("Saturating", Some("ScalarArith"), &[], "combine"),
+        ImplDescriptor::new("Wrapping", Some("Mul"), &["Self"], "mul"),
+        ImplDescriptor::new("Saturating", Some("Mul"), &["Self"], "mul"),
+        // Case 54
+        ImplDescriptor::new("&Scalar", Some("Mul"), &["&Scalar"], "mul"),
+        // Case 55
+        ImplDescriptor::new("Container<TypeA>", Some("From"), &["&Point"], "from"),
+        // Case 56
+        ImplDescriptor::new("Scalar", Some("AsRef"), &["i32"], "as_ref"),
+        ImplDescriptor::new("Scalar", Some("AsMut"), &["i32"], "as_mut"),
+        // Case 57
+        ImplDescriptor::new("Vec2", Some("Neg"), &[], "neg").in_module("geometry"),
+        // Case 58
+        ImplDescriptor::new("Scalar", Some("Binary"), &[], "fmt"),
+        ImplDescriptor::new("Scalar", Some("Octal"), &[], "fmt"),
+        // Case 59
+        ImplDescriptor::new("&Point", Some("Mul"), &["Scalar"], "mul"),
+        // Case 60
+        ImplDescriptor::new("Point", Some("PartialOrd"), &["Self"], "partial_cmp"),
+        ImplDescriptor::new("Point", Some("Ord"), &[], "cmp"),
+        // Case 61
+        ImplDescriptor::new("Point", Some("Add"), &["Self"], "add"),
+        // Case 62
+        ImplDescriptor::new("Scalar", Some("ToPoint"), &[], "to_point"),
+    ]);
+    // Case 63
+    impls.extend(container_neg_impls());
+    impls.extend([
+        // Case 64
+        ImplDescriptor::new("SignError", Some("Display"), &[], "fmt"),
+        ImplDescriptor::new("Sign", Some("TryFrom"), &["&Scalar"], "try_from"),
+        // Case 65
+        ImplDescriptor::new("Scalar", Some("Mul"), &["Container<TypeA>"], "mul"),
+        ImplDescriptor::new("Scalar", Some("Mul"), &["Container<TypeB>"], "mul"),
+    ]);
+    symbols::assign_source_lines(&mut impls, include_str!("lib.rs"));
+    impls
+}
+
+#[cfg(test)]
+// The tests deliberately take references to exercise the reference impls.
+#[allow(clippy::op_ref)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neg_owned() {
+        let s = Scalar(5);
+        assert_eq!((-s).0, -5);
+    }
+
+    #[test]
+    fn test_neg_ref() {
+        let s = Scalar(5);
+        assert_eq!((-&s).0, -5);
+    }
+
+    #[test]
+    fn test_mul_point_by_scalar() {
+        let p = Point(2, 3);
+        let s = Scalar(4);
+        let result = &p * &s;
+        assert_eq!(result.0, 8);
+        assert_eq!(result.1, 12);
+    }
+
+    #[test]
+    fn test_mul_scalar_by_point() {
+        let s = Scalar(4);
+        let p = Point(2, 3);
+        let result = &s * &p;
+        assert_eq!(result.0, 8);
+        assert_eq!(result.1, 12);
+    }
+
+    #[test]
+    fn test_from_scalar_to_container_a() {
+        let s = Scalar(5);
+        let _c: Container<TypeA> = Container::from(&s);
+        // Just verify it compiles and runs
+    }
+
+    #[test]
+    fn test_from_scalar_to_container_b() {
+        let s = Scalar(5);
+        let _c: Container<TypeB> = Container::from(&s);
+        // Just verify it compiles and runs
+    }
+
+    /// The documented `(path, rust-analyzer, verus-analyzer)` symbols of every
+    /// impl in `all_crate_impls()`, in catalog order. Every new impl must be
+    /// added here.
+    static EXPECTED: &[(&str, &str, &str)] = &[
+        (
+            "<Scalar as Neg>::neg",
+            "impl#[Scalar][Neg]neg().",
+            "Scalar#Neg#neg().",
+        ),
+        (
+            "<&Scalar as Neg>::neg",
+            "impl#[`&Scalar`][Neg]neg().",
+            "Neg#neg().",
+        ),
+        (
+            "<&Point as Mul<&Scalar>>::mul",
+            "impl#[`&Point`][`Mul<&Scalar>`]mul().",
+            "Mul#mul().",
+        ),
+        (
+            "<&Scalar as Mul<&Point>>::mul",
+            "impl#[`&Scalar`][`Mul<&Point>`]mul().",
+            "Mul#mul().",
+        ),
+        (
+            "<Container<TypeA> as From<&Scalar>>::from",
+            "impl#[`Container<TypeA>`][`From<&Scalar>`]from().",
+            "Container#From#from().",
+        ),
+        (
+            "<Container<TypeB> as From<&Scalar>>::from",
+            "impl#[`Container<TypeB>`][`From<&Scalar>`]from().",
+            "Container#From#from().",
+        ),
+        (
+            "<Scalar<T> as Add<Self>>::add",
+            "impl#[`Scalar<T>`][`Add<Self>`]add().",
+            "Scalar#Add#add().",
+        ),
+        (
+            "<&Scalar<T> as Add<&Scalar<T>>>::add",
+            "impl#[`&Scalar<T>`][`Add<&Scalar<T>>`]add().",
+            "Add#add().",
+        ),
+        (
+            "<&Scalar<T> as Add<Scalar<T>>>::add",
+            "impl#[`&Scalar<T>`][`Add<Scalar<T>>`]add().",
+            "Add#add().",
+        ),
+        (
+            "<Scalar<T> as Sub<Self>>::sub",
+            "impl#[`Scalar<T>`][`Sub<Self>`]sub().",
+            "Scalar#Sub#sub().",
+        ),
+        (
+            "<Point as Sub<Self>>::sub",
+            "impl#[Point][`Sub<Self>`]sub().",
+            "Point#Sub#sub().",
+        ),
+        (
+            "<&Point as Sub<&Scalar>>::sub",
+            "impl#[`&Point`][`Sub<&Scalar>`]sub().",
+            "Sub#sub().",
+        ),
+        (
+            "Scalar::checked_neg",
+            "impl#[Scalar]checked_neg().",
+            "Scalar#checked_neg().",
+        ),
+        (
+            "Scalar::checked_mul",
+            "impl#[Scalar]checked_mul().",
+            "Scalar#checked_mul().",
+        ),
+        (
+            "Point::checked_mul_scalar",
+            "impl#[Point]checked_mul_scalar().",
+            "Point#checked_mul_scalar().",
+        ),
+        (
+            "<Vector<N> as Neg>::neg",
+            "impl#[`Vector<N>`][Neg]neg().",
+            "Vector#Neg#neg().",
+        ),
+        (
+            "<Vector<N> as Add<Self>>::add",
+            "impl#[`Vector<N>`][`Add<Self>`]add().",
+            "Vector#Add#add().",
+        ),
+        (
+            "<Ref<'a> as Neg>::neg",
+            "impl#[`Ref<'a>`][Neg]neg().",
+            "Ref#Neg#neg().",
+        ),
+        (
+            "<Container<T> as From<Scalar>>::from",
+            "impl#[`Container<T>`][`From<Scalar>`]from().",
+            "Container#From#from().",
+        ),
+        (
+            "<T as Double>::double",
+            "impl#[T][Double]double().",
+            "Double#double().",
+        ),
+        (
+            "<Scalar as Magnitude>::magnitude",
+            "impl#[Scalar][Magnitude]magnitude().",
+            "Scalar#Magnitude#magnitude().",
+        ),
+        (
+            "<Scalar as Describe>::label",
+            "impl#[Scalar][Describe]label().",
+            "Scalar#Describe#label().",
+        ),
+        (
+            "<Scalar as From<i32>>::from",
+            "impl#[Scalar][`From<i32>`]from().",
+            "Scalar#From#from().",
+        ),
+        (
+            "<Point as From<(i32, i32)>>::from",
+            "impl#[Point][`From<(i32, i32)>`]from().",
+            "Point#From#from().",
+        ),
+        (
+            "<Scalar as Display>::fmt",
+            "impl#[Scalar][Display]fmt().",
+            "Scalar#Display#fmt().",
+        ),
+        (
+            "<Point as Display>::fmt",
+            "impl#[Point][Display]fmt().",
+            "Point#Display#fmt().",
+        ),
+        (
+            "ParseScalarError::kind",
+            "impl#[ParseScalarError]kind().",
+            "ParseScalarError#kind().",
+        ),
+        (
+            "<ParseScalarError as Display>::fmt",
+            "impl#[ParseScalarError][Display]fmt().",
+            "ParseScalarError#Display#fmt().",
+        ),
+        (
+            "<Scalar as FromStr>::from_str",
+            "impl#[Scalar][FromStr]from_str().",
+            "Scalar#FromStr#from_str().",
+        ),
+        (
+            "<ParsePointError as Display>::fmt",
+            "impl#[ParsePointError][Display]fmt().",
+            "ParsePointError#Display#fmt().",
+        ),
+        (
+            "<Point as FromStr>::from_str",
+            "impl#[Point][FromStr]from_str().",
+            "Point#FromStr#from_str().",
+        ),
+        (
+            "<Point as Index<usize>>::index",
+            "impl#[Point][`Index<usize>`]index().",
+            "Point#Index#index().",
+        ),
+        (
+            "<Point as IndexMut<usize>>::index_mut",
+            "impl#[Point][`IndexMut<usize>`]index_mut().",
+            "Point#IndexMut#index_mut().",
+        ),
+        (
+            "<Point as IntoIterator>::into_iter",
+            "impl#[Point][IntoIterator]into_iter().",
+            "Point#IntoIterator#into_iter().",
+        ),
+        (
+            "<&Point as IntoIterator>::into_iter",
+            "impl#[`&Point`][IntoIterator]into_iter().",
+            "IntoIterator#into_iter().",
+        ),
+        (
+            "<Pair<TypeA, TypeB> as From<&Scalar>>::from",
+            "impl#[`Pair<TypeA, TypeB>`][`From<&Scalar>`]from().",
+            "Pair#From#from().",
+        ),
+        (
+            "<Pair<TypeB, TypeA> as From<&Scalar>>::from",
+            "impl#[`Pair<TypeB, TypeA>`][`From<&Scalar>`]from().",
+            "Pair#From#from().",
+        ),
+        (
+            "<Container<Container<TypeA>> as From<&Scalar>>::from",
+            "impl#[`Container<Container<TypeA>>`][`From<&Scalar>`]from().",
+            "Container#From#from().",
+        ),
+        (
+            "Scalar::convert",
+            "impl#[Scalar]convert().",
+            "Scalar#convert().",
+        ),
+        (
+            "<(Scalar, Scalar) as ElementwiseNeg>::neg",
+            "impl#[`(Scalar, Scalar)`][ElementwiseNeg]neg().",
+            "ElementwiseNeg#neg().",
+        ),
+        (
+            "<[Scalar; 2] as ElementwiseNeg>::neg",
+            "impl#[`[Scalar; 2]`][ElementwiseNeg]neg().",
+            "ElementwiseNeg#neg().",
+        ),
+        (
+            "<Scalar as PartialEq<Self>>::eq",
+            "impl#[Scalar][`PartialEq<Self>`]eq().",
+            "Scalar#PartialEq#eq().",
+        ),
+        (
+            "<Point as PartialEq<Self>>::eq",
+            "impl#[Point][`PartialEq<Self>`]eq().",
+            "Point#PartialEq#eq().",
+        ),
+        (
+            "<Scalar as PartialOrd<Self>>::partial_cmp",
+            "impl#[Scalar][`PartialOrd<Self>`]partial_cmp().",
+            "Scalar#PartialOrd#partial_cmp().",
+        ),
+        (
+            "<Scalar as Ord>::cmp",
+            "impl#[Scalar][Ord]cmp().",
+            "Scalar#Ord#cmp().",
+        ),
+        (
+            "<Scalar as Hash>::hash",
+            "impl#[Scalar][Hash]hash().",
+            "Scalar#Hash#hash().",
+        ),
+        (
+            "<Point as Hash>::hash",
+            "impl#[Point][Hash]hash().",
+            "Point#Hash#hash().",
+        ),
+        (
+            "<ArithmeticError as Display>::fmt",
+            "impl#[ArithmeticError][Display]fmt().",
+            "ArithmeticError#Display#fmt().",
+        ),
+        (
+            "Scalar::try_mul",
+            "impl#[Scalar]try_mul().",
+            "Scalar#try_mul().",
+        ),
+        (
+            "Point::try_scale",
+            "impl#[Point]try_scale().",
+            "Point#try_scale().",
+        ),
+        (
+            "<ScalarRangeError as Display>::fmt",
+            "impl#[ScalarRangeError][Display]fmt().",
+            "ScalarRangeError#Display#fmt().",
+        ),
+        (
+            "<Scalar as TryFrom<i64>>::try_from",
+            "impl#[Scalar][`TryFrom<i64>`]try_from().",
+            "Scalar#TryFrom#try_from().",
+        ),
+        (
+            "<Point as Neg>::neg",
+            "impl#[Point][Neg]neg().",
+            "Point#Neg#neg().",
+        ),
+        (
+            "<&Point as Neg>::neg",
+            "impl#[`&Point`][Neg]neg().",
+            "Neg#neg().",
+        ),
+        (
+            "<Scalar as AddAssign<Self>>::add_assign",
+            "impl#[Scalar][`AddAssign<Self>`]add_assign().",
+            "Scalar#AddAssign#add_assign().",
+        ),
+        (
+            "<Scalar as MulAssign<Scalar>>::mul_assign",
+            "impl#[Scalar][`MulAssign<Scalar>`]mul_assign().",
+            "Scalar#MulAssign#mul_assign().",
+        ),
+        (
+            "<Scalar as Sum<Self>>::sum",
+            "impl#[Scalar][`Sum<Self>`]sum().",
+            "Scalar#Sum#sum().",
+        ),
+        (
+            "<Scalar as Product<Self>>::product",
+            "impl#[Scalar][`Product<Self>`]product().",
+            "Scalar#Product#product().",
+        ),
+        (
+            "Tagged<T>::new",
+            "impl#[`Tagged<T>`]new().",
+            "Tagged#new().",
+        ),
+        (
+            "<Tagged<T> as Neg>::neg",
+            "impl#[`Tagged<T>`][Neg]neg().",
+            "Tagged#Neg#neg().",
+        ),
+        (
+            "<Scalar as Apply>::apply",
+            "impl#[Scalar][Apply]apply().",
+            "Scalar#Apply#apply().",
+        ),
+        (
+            "Scalar::apply_ref",
+            "impl#[Scalar]apply_ref().",
+            "Scalar#apply_ref().",
+        ),
+        (
+            "<BigScalar as Neg>::neg",
+            "impl#[BigScalar][Neg]neg().",
+            "BigScalar#Neg#neg().",
+        ),
+        (
+            "<ScalarWrapper as Deref>::deref",
+            "impl#[ScalarWrapper][Deref]deref().",
+            "ScalarWrapper#Deref#deref().",
+        ),
+        (
+            "<Sign as Neg>::neg",
+            "impl#[Sign][Neg]neg().",
+            "Sign#Neg#neg().",
+        ),
+        (
+            "<Sign as From<Scalar>>::from",
+            "impl#[Sign][`From<Scalar>`]from().",
+            "Sign#From#from().",
+        ),
+        (
+            "<Scalar as Mul<i32>>::mul",
+            "impl#[Scalar][`Mul<i32>`]mul().",
+            "Scalar#Mul#mul().",
+        ),
+        (
+            "<i32 as Mul<Scalar>>::mul",
+            "impl#[i32][`Mul<Scalar>`]mul().",
+            "Mul#mul().",
+        ),
+        (
+            "<Point as Mul<Scalar>>::mul",
+            "impl#[Point][`Mul<Scalar>`]mul().",
+            "Point#Mul#mul().",
+        ),
+        (
+            "<Scalar as Mul<Point>>::mul",
+            "impl#[Scalar][`Mul<Point>`]mul().",
+            "Scalar#Mul#mul().",
+        ),
+        (
+            "<Container<T> as Clone>::clone",
+            "impl#[`Container<T>`][Clone]clone().",
+            "Container#Clone#clone().",
+        ),
+        (
+            "<Scalar as From<Point>>::from",
+            "impl#[Scalar][`From<Point>`]from().",
+            "Scalar#From#from().",
+        ),
+        (
+            "<Scalar as Mul<Self>>::mul",
+            "impl#[Scalar][`Mul<Self>`]mul().",
+            "Scalar#Mul#mul().",
+        ),
+        (
+            "<&Scalar as Mul<Scalar>>::mul",
+            "impl#[`&Scalar`][`Mul<Scalar>`]mul().",
+            "Mul#mul().",
+        ),
+        (
+            "<Box<Scalar> as Neg>::neg",
+            "impl#[`Box<Scalar>`][Neg]neg().",
+            "Box#Neg#neg().",
+        ),
+        (
+            "<Scalar as Default>::default",
+            "impl#[Scalar][Default]default().",
+            "Scalar#Default#default().",
+        ),
+        (
+            "<Point as Default>::default",
+            "impl#[Point][Default]default().",
+            "Point#Default#default().",
+        ),
+        (
+            "Scalar::try_div",
+            "impl#[Scalar]try_div().",
+            "Scalar#try_div().",
+        ),
+        (
+            "Scalar::try_rem",
+            "impl#[Scalar]try_rem().",
+            "Scalar#try_rem().",
+        ),
+        (
+            "<Scalar as Div<Self>>::div",
+            "impl#[Scalar][`Div<Self>`]div().",
+            "Scalar#Div#div().",
+        ),
+        (
+            "<Scalar as Rem<Self>>::rem",
+            "impl#[Scalar][`Rem<Self>`]rem().",
+            "Scalar#Rem#rem().",
+        ),
+        (
+            "<Scalar as PartialEq<i32>>::eq",
+            "impl#[Scalar][`PartialEq<i32>`]eq().",
+            "Scalar#PartialEq#eq().",
+        ),
+        (
+            "<Scalar<i64> as From<i32>>::from",
+            "impl#[`Scalar<i64>`][`From<i32>`]from().",
+            "Scalar#From#from().",
+        ),
+        (
+            "<Point as Shape>::area",
+            "impl#[Point][Shape]area().",
+            "Point#Shape#area().",
+        ),
+        (
+            "<Box<dyn Shape> as Shape>::area",
+            "impl#[`Box<dyn Shape>`][Shape]area().",
+            "Box#Shape#area().",
+        ),
+        (
+            "dyn Shape::is_degenerate",
+            "impl#[`dyn Shape`]is_degenerate().",
+            "is_degenerate().",
+        ),
+        (
+            "ScalarRange::new",
+            "impl#[ScalarRange]new().",
+            "ScalarRange#new().",
+        ),
+        (
+            "<ScalarRange as Iterator>::next",
+            "impl#[ScalarRange][Iterator]next().",
+            "ScalarRange#Iterator#next().",
+        ),
+        (
+            "<*const Scalar as RawNeg>::raw_neg",
+            "impl#[`*const Scalar`][RawNeg]raw_neg().",
+            "RawNeg#raw_neg().",
+        ),
+        (
+            "<Wrapping as ScalarArith>::combine",
+            "impl#[Wrapping][ScalarArith]combine().",
+            "Wrapping#ScalarArith#combine().",
+        ),
+        (
+            "<Saturating as ScalarArith>::combine",
+            "impl#[Saturating][ScalarArith]combine().",
+            "Saturating#ScalarArith#combine().",
+        ),
+        (
+            "<Wrapping as Mul<Self>>::mul",
+            "impl#[Wrapping][`Mul<Self>`]mul().",
+            "Wrapping#Mul#mul().",
+        ),
+        (
+            "<Saturating as Mul<Self>>::mul",
+            "impl#[Saturating][`Mul<Self>`]mul().",
+            "Saturating#Mul#mul().",
+        ),
+        (
+            "<&Scalar as Mul<&Scalar>>::mul",
+            "impl#[`&Scalar`][`Mul<&Scalar>`]mul().",
+            "Mul#mul().",
+        ),
+        (
+            "<Container<TypeA> as From<&Point>>::from",
+            "impl#[`Container<TypeA>`][`From<&Point>`]from().",
+            "Container#From#from().",
+        ),
+        (
+            "<Scalar as AsRef<i32>>::as_ref",
+            "impl#[Scalar][`AsRef<i32>`]as_ref().",
+            "Scalar#AsRef#as_ref().",
+        ),
+        (
+            "<Scalar as AsMut<i32>>::as_mut",
+            "impl#[Scalar][`AsMut<i32>`]as_mut().",
+            "Scalar#AsMut#as_mut().",
+        ),
+        (
+            "<geometry::Vec2 as Neg>::neg",
+            "geometry/impl#[Vec2][Neg]neg().",
+            "geometry/Vec2#Neg#neg().",
+        ),
+        (
+            "<Scalar as Binary>::fmt",
+            "impl#[Scalar][Binary]fmt().",
+            "Scalar#Binary#fmt().",
+        ),
+        (
+            "<Scalar as Octal>::fmt",
+            "impl#[Scalar][Octal]fmt().",
+            "Scalar#Octal#fmt().",
+        ),
+        (
+            "<&Point as Mul<Scalar>>::mul",
+            "impl#[`&Point`][`Mul<Scalar>`]mul().",
+            "Mul#mul().",
+        ),
+        (
+            "<Point as PartialOrd<Self>>::partial_cmp",
+            "impl#[Point][`PartialOrd<Self>`]partial_cmp().",
+            "Point#PartialOrd#partial_cmp().",
+        ),
+        (
+            "<Point as Ord>::cmp",
+            "impl#[Point][Ord]cmp().",
+            "Point#Ord#cmp().",
+        ),
+        (
+            "<Point as Add<Self>>::add",
+            "impl#[Point][`Add<Self>`]add().",
+            "Point#Add#add().",
+        ),
+        (
+            "<Scalar as ToPoint>::to_point",
+            "impl#[Scalar][ToPoint]to_point().",
+            "Scalar#ToPoint#to_point().",
+        ),
+        (
+            "<Container<T> as Neg>::neg",
+            "impl#[`Container<T>`][Neg]neg().",
+            "Container#Neg#neg().",
+        ),
+        (
+            "<SignError as Display>::fmt",
+            "impl#[SignError][Display]fmt().",
+            "SignError#Display#fmt().",
+        ),
+        (
+            "<Sign as TryFrom<&Scalar>>::try_from",
+            "impl#[Sign][`TryFrom<&Scalar>`]try_from().",
+            "Sign#TryFrom#try_from().",
+        ),
+        (
+            "<Scalar as Mul<Container<TypeA>>>::mul",
+            "impl#[Scalar][`Mul<Container<TypeA>>`]mul().",
+            "Scalar#Mul#mul().",
+        ),
+        (
+            "<Scalar as Mul<Container<TypeB>>>::mul",
+            "impl#[Scalar][`Mul<Container<TypeB>>`]mul().",
+            "Scalar#Mul#mul().",
+        ),
+    ];
+
+    #[test]
+    fn symbol_conformance_matrix() {
+        let impls = all_crate_impls();
+        assert_eq!(
+            impls.len(),
+            EXPECTED.len(),
+            "EXPECTED does not cover every impl"
+        );
+        for (descriptor, &(path, ra, va)) in impls.iter().zip(EXPECTED) {
+            assert_eq!(descriptor.to_string(), path, "impl out of catalog order");
+            for (format, expected) in [
+                (SymbolFormat::RustAnalyzer, ra),
+                (SymbolFormat::VerusAnalyzer, va),
+            ] {
+                assert_eq!(
+                    descriptor.to_symbol(format),
+                    expected,
+                    "wrong {:?} symbol for {}",
+                    format,
+                    descriptor
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_owned() {
+        assert_eq!((Scalar(2) + Scalar(3)).0, 5);
+    }
+
+    #[test]
+    fn test_add_ref() {
+        let a = Scalar(2);
+        let b = Scalar(3);
+        assert_eq!((&a + &b).0, 5);
+        assert_eq!((&a + b).0, 5);
+    }
+
+    #[test]
+    fn test_sub_scalar() {
+        assert_eq!((Scalar(5) - Scalar(3)).0, 2);
+    }
+
+    #[test]
+    fn test_sub_point() {
+        let result = Point(5, 7) - Point(2, 3);
+        assert_eq!(result.0, 3);
+        assert_eq!(result.1, 4);
+    }
+
+    #[test]
+    fn test_sub_point_by_scalar() {
+        let result = &Point(5, 7) - &Scalar(2);
+        assert_eq!(result.0, 3);
+        assert_eq!(result.1, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn test_sub_point_by_scalar_overflow() {
+        let _ = &Point(0, i32::MIN) - &Scalar(1);
+    }
+
+    #[test]
+    fn test_checked_neg() {
+        assert_eq!(Scalar(5).checked_neg().map(|s| s.0), Some(-5));
+        assert!(Scalar(i32::MIN).checked_neg().is_none());
+    }
+
+    #[test]
+    fn test_checked_mul() {
+        assert_eq!(Scalar(3).checked_mul(Scalar(4)).map(|s| s.0), Some(12));
+        assert!(Scalar(i32::MAX).checked_mul(Scalar(2)).is_none());
+    }
+
+    #[test]
+    fn test_checked_mul_scalar() {
+        let result = Point(2, 3).checked_mul_scalar(Scalar(4)).unwrap();
+        assert_eq!((result.0, result.1), (8, 12));
+        assert!(Point(1, i32::MAX).checked_mul_scalar(Scalar(2)).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to negate with overflow")]
+    fn test_neg_overflow_panics() {
+        let _ = -Scalar(i32::MIN);
+    }
+
+    #[test]
+    fn test_generic_scalar_i64() {
+        let big = Scalar::<i64>(5) + Scalar(i64::from(i32::MAX));
+        assert_eq!(big.0, 2_147_483_652);
+        assert_eq!((Scalar::<i64>(5) - Scalar(7)).0, -2);
+        assert_eq!((&Scalar::<u32>(5) + Scalar(1)).0, 6);
+    }
+
+    #[test]
+    fn test_neg_vector() {
+        let v = Vector::<3>([1, 2, 3]);
+        assert_eq!((-v).0, [-1, -2, -3]);
+    }
+
+    #[test]
+    fn test_add_vector() {
+        let sum = Vector([1, 2]) + Vector([10, 20]);
+        assert_eq!(sum.0, [11, 22]);
+    }
+
+    #[test]
+    fn test_neg_ref_wrapper() {
+        let s = Scalar(5);
+        let r = Ref(&s);
+        assert_eq!((-r).0, -5);
+    }
+
+    #[test]
+    fn test_from_scalar_to_default_container() {
+        let c = Container::<i32>::from(Scalar(1));
+        assert_eq!(c.value, 0);
+    }
+
+    #[test]
+    fn test_associated_const_names() {
+        assert_eq!(<Scalar as Named>::NAME, "Scalar");
+        assert_eq!(<Point as Named>::NAME, "Point");
+    }
+
+    #[test]
+    fn test_blanket_double() {
+        assert_eq!(Scalar(3).double().0, 6);
+        assert_eq!(4.double(), 8);
+    }
+
+    #[test]
+    fn test_magnitude() {
+        assert_eq!(Scalar(-4).magnitude(), 4);
+        assert_eq!(Scalar(4).magnitude(), 4);
+    }
+
+    #[test]
+    fn test_describe_override_and_default() {
+        assert_eq!(Scalar(1).label(), "scalar 1");
+        assert_eq!(Point(1, 2).label(), "unknown");
+    }
+
+    #[test]
+    fn test_from_i32() {
+        assert_eq!(Scalar::<i32>::from(7).0, 7);
+    }
+
+    #[test]
+    fn test_from_tuple() {
+        let p = Point::from((2, 3));
+        assert_eq!((p.0, p.1), (2, 3));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Scalar(5)), "Scalar(5)");
+        assert_eq!(format!("{}", Point(2, 3)), "Point(2, 3)");
+    }
+
+    #[test]
+    fn test_parse_scalar() {
+        assert_eq!("5".parse::<Scalar>().unwrap().0, 5);
+        assert_eq!("Scalar(-5)".parse::<Scalar>().unwrap().0, -5);
+        let round_trip: Scalar = Scalar(42).to_string().parse().unwrap();
+        assert_eq!(round_trip.0, 42);
+    }
+
+    #[test]
+    fn test_parse_scalar_errors() {
+        assert_eq!(
+            "2147483648".parse::<Scalar>().unwrap_err().kind(),
+            &IntErrorKind::PosOverflow
+        );
+        assert_eq!(
+            "Scalar(five)".parse::<Scalar>().unwrap_err().kind(),
+            &IntErrorKind::InvalidDigit
+        );
+    }
+
+    #[test]
+    fn test_parse_point() {
+        let p: Point = "(2, 3)".parse().unwrap();
+        assert_eq!((p.0, p.1), (2, 3));
+        let round_trip: Point = Point(-1, 7).to_string().parse().unwrap();
+        assert_eq!((round_trip.0, round_trip.1), (-1, 7));
+    }
+
+    #[test]
+    fn test_parse_point_errors() {
+        assert_eq!(
+            "2, 3".parse::<Point>().unwrap_err(),
+            ParsePointError::Malformed
+        );
+        assert_eq!(
+            "(2 3)".parse::<Point>().unwrap_err(),
+            ParsePointError::Malformed
+        );
+        assert!(matches!(
+            "(2, 99999999999)".parse::<Point>(),
+            Err(ParsePointError::Component(err)) if err.kind() == &IntErrorKind::PosOverflow
+        ));
+    }
+
+    #[test]
+    fn test_index_point() {
+        let mut p = Point(2, 3);
+        assert_eq!((p[0], p[1]), (2, 3));
+        p[0] = 7;
+        p[1] += 1;
+        assert_eq!((p.0, p.1), (7, 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_index_point_out_of_range() {
+        let p = Point(2, 3);
+        let _ = p[2];
+    }
+
+    #[test]
+    fn test_into_iter_point() {
+        assert_eq!(Point(2, 3).into_iter().collect::<Vec<_>>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_into_iter_point_ref() {
+        let p = Point(2, 3);
+        let mut sum = 0;
+        for component in &p {
+            sum += component;
+        }
+        assert_eq!(sum, 5);
+    }
+
+    #[test]
+    fn test_from_scalar_to_pairs() {
+        let s = Scalar(5);
+        let ab: Pair<TypeA, TypeB> = Pair::from(&s);
+        let ba: Pair<TypeB, TypeA> = Pair::from(&s);
+        assert_eq!(format!("{:?}", ab), "Pair { first: TypeA, second: TypeB }");
+        assert_eq!(format!("{:?}", ba), "Pair { first: TypeB, second: TypeA }");
+    }
+
+    #[test]
+    fn test_from_scalar_to_nested_container() {
+        let s = Scalar(5);
+        let c: Container<Container<TypeA>> = Container::from(&s);
+        let TypeA = c.value.value;
+    }
+
+    #[test]
+    fn test_convert() {
+        let c: Container<i32> = Scalar(1).convert();
+        assert_eq!(c.value, 0);
+    }
+
+    #[test]
+    fn test_neg_tuple() {
+        let (a, b) = (Scalar(1), Scalar(2)).neg();
+        assert_eq!((a.0, b.0), (-1, -2));
+    }
+
+    #[test]
+    fn test_neg_array() {
+        let [a, b] = [Scalar(1), Scalar(2)].neg();
+        assert_eq!((a.0, b.0), (-1, -2));
+    }
+
+    #[test]
+    fn test_scalar_eq() {
+        assert_eq!(Scalar(3), Scalar(3));
+        assert_ne!(Scalar(3), Scalar(4));
+    }
+
+    #[test]
+    fn test_point_eq() {
+        assert_eq!(Point(1, 2), Point(1, 2));
+        assert_ne!(Point(1, 2), Point(1, 3));
+        assert_ne!(Point(1, 2), Point(2, 2));
+    }
+
+    #[test]
+    fn test_sort_scalars() {
+        let mut scalars = vec![Scalar(3), Scalar(-1), Scalar(2)];
+        scalars.sort();
+        assert_eq!(scalars, [Scalar(-1), Scalar(2), Scalar(3)]);
+        assert!(Scalar(1) < Scalar(2));
+    }
+
+    #[test]
+    fn test_hash_set_membership() {
+        let mut points = std::collections::HashSet::new();
+        points.insert(Point(1, 2));
+        assert!(points.contains(&Point(1, 2)));
+        assert!(!points.contains(&Point(2, 1)));
+
+        let scalars: std::collections::HashSet<Scalar> = [Scalar(1), Scalar(1)].into();
+        assert_eq!(scalars.len(), 1);
+    }
+
+    #[test]
+    fn test_try_mul() {
+        assert_eq!(Scalar(6).try_mul(Scalar(7)), Ok(Scalar(42)));
+        assert_eq!(
+            Scalar(i32::MAX).try_mul(Scalar(2)),
+            Err(ArithmeticError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_try_scale() {
+        assert_eq!(Point(2, 3).try_scale(Scalar(4)), Ok(Point(8, 12)));
+        assert_eq!(
+            Point(1, i32::MAX).try_scale(Scalar(2)),
+            Err(ArithmeticError::Overflow)
+        );
+        assert_eq!(ArithmeticError::Overflow.to_string(), "arithmetic overflow");
+    }
+
+    #[test]
+    fn test_try_from_i64() {
+        assert_eq!(Scalar::try_from(5i64), Ok(Scalar(5)));
+        assert!(Scalar::try_from(i64::from(i32::MIN)).is_ok());
+        assert!(Scalar::try_from(i64::MAX).is_err());
+        assert!(Scalar::try_from(i64::from(i32::MAX) + 1).is_err());
+    }
+
+    #[test]
+    fn test_neg_point() {
+        assert_eq!(-Point(1, 2), Point(-1, -2));
+        assert_eq!(-&Point(1, -2), Point(-1, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to negate with overflow")]
+    fn test_neg_point_overflow() {
+        let _ = -Point(0, i32::MIN);
+    }
+
+    #[test]
+    fn test_compound_assignment() {
+        let mut s = Scalar(2);
+        s += Scalar(3);
+        assert_eq!(s, Scalar(5));
+        s *= Scalar(4);
+        assert_eq!(s, Scalar(20));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_add_assign_overflow() {
+        let mut s = Scalar(i32::MAX);
+        s += Scalar(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn test_mul_assign_overflow() {
+        let mut s = Scalar(i32::MAX);
+        s *= Scalar(2);
+    }
+
+    #[test]
+    fn test_sum_and_product() {
+        let scalars = [Scalar(1), Scalar(2), Scalar(3)];
+        assert_eq!(scalars.into_iter().sum::<Scalar>(), Scalar(6));
+        assert_eq!(scalars.into_iter().product::<Scalar>(), Scalar(6));
+        assert_eq!(std::iter::empty().sum::<Scalar>(), Scalar(0));
+        assert_eq!(std::iter::empty().product::<Scalar>(), Scalar(1));
+    }
+
+    #[test]
+    fn test_inherent_consts() {
+        assert_eq!(Scalar::ZERO.0, 0);
+        assert_eq!(Point::ORIGIN, Point(0, 0));
+        assert_eq!(Scalar(5) + Scalar::ZERO, Scalar(5));
+    }
+
+    #[test]
+    fn test_neg_tagged() {
+        let a: Tagged<TypeA> = -Tagged::new(4);
+        let b: Tagged<TypeB> = -Tagged::new(-7);
+        assert_eq!(a.value, -4);
+        assert_eq!(b.value, 7);
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_eq!(Scalar(3).apply(|x| x * 2), Scalar(6));
+        assert_eq!(Scalar(3).apply_ref(|x| x), Scalar(3));
+    }
+
+    #[test]
+    fn test_neg_big_scalar() {
+        assert_eq!(-BigScalar(vec![1, 2]), BigScalar(vec![-1, -2]));
+    }
+
+    #[test]
+    fn test_deref_wrapper() {
+        let wrapper = ScalarWrapper(Scalar(4));
+        assert_eq!(wrapper.checked_neg(), Some(Scalar(-4)));
+        assert_eq!(*wrapper, Scalar(4));
+    }
+
+    #[test]
+    fn test_neg_sign() {
+        assert_eq!(-Sign::Positive, Sign::Negative);
+        assert_eq!(-Sign::Negative, Sign::Positive);
+    }
+
+    #[test]
+    fn test_sign_from_scalar() {
+        assert_eq!(Sign::from(Scalar(-3)), Sign::Negative);
+        assert_eq!(Sign::from(Scalar(0)), Sign::Positive);
+        assert_eq!(Sign::from(Scalar(3)), Sign::Positive);
+    }
+
+    #[test]
+    fn test_mul_by_i32() {
+        assert_eq!(Scalar(2) * 3, Scalar(6));
+        assert_eq!(3 * Scalar(2), Scalar(6));
+    }
+
+    #[test]
+    fn test_registered_impls_are_cataloged() {
+        let registered = registered_impls();
+        assert_eq!(
+            registered,
+            [
+                ImplDescriptor::new("Sign", Some("Neg"), &[], "neg"),
+                ImplDescriptor::new("Sign", Some("From"), &["Scalar"], "from"),
+                ImplDescriptor::new("Container<T>", Some("Neg"), &[], "neg"),
+            ]
+        );
+        let all: Vec<String> = all_crate_impls()
+            .iter()
+            .map(|d| d.to_symbol(SymbolFormat::RustAnalyzer))
+            .collect();
+        assert!(registered
+            .iter()
+            .all(|d| all.contains(&d.to_symbol(SymbolFormat::RustAnalyzer))));
+    }
+
+    #[test]
+    fn test_mul_owned_point_and_scalar() {
+        assert_eq!(Point(2, 3) * Scalar(4), Point(8, 12));
+        assert_eq!(Scalar(4) * Point(2, 3), Point(8, 12));
+    }
+
+    #[test]
+    fn test_clone_container() {
+        let a = Container { value: TypeA };
+        let _: Container<TypeA> = a.clone();
+        let s = Container { value: Scalar(3) };
+        assert_eq!(s.clone().value, Scalar(3));
+    }
+
+    #[test]
+    fn test_scalar_from_point() {
+        assert_eq!(Scalar::from(Point(2, 3)).0, 5);
+    }
+
+    #[test]
+    fn test_mul_scalars() {
+        assert_eq!(Scalar(3) * Scalar(4), Scalar(12));
+    }
+
+    #[test]
+    fn test_owned_mul_is_not_in_reference_collision() {
+        let owned = ImplDescriptor::new("Scalar", Some("Mul"), &["Self"], "mul");
+        let by_ref = ImplDescriptor::new("&Point", Some("Mul"), &["&Scalar"], "mul");
+        assert_eq!(
+            owned.to_symbol(SymbolFormat::VerusAnalyzer),
+            "Scalar#Mul#mul()."
+        );
+        assert_ne!(
+            owned.to_symbol(SymbolFormat::VerusAnalyzer),
+            by_ref.to_symbol(SymbolFormat::VerusAnalyzer)
+        );
+    }
+
+    #[test]
+    fn test_mul_ref_scalar_to_i32() {
+        assert_eq!(&Scalar(3) * Scalar(4), 12);
+    }
+
+    #[test]
+    fn test_neg_boxed_scalar() {
+        assert_eq!((-Box::new(Scalar(5))).0, -5);
+    }
+
+    #[test]
+    fn test_defaults() {
+        assert_eq!(Scalar::default(), Scalar(0));
+        assert_eq!(Point::default(), Point(0, 0));
+    }
+
+    #[test]
+    fn test_div_and_rem() {
+        assert_eq!(Scalar(7) / Scalar(2), Scalar(3));
+        assert_eq!(Scalar(7) % Scalar(2), Scalar(1));
+        assert_eq!(
+            Scalar(7).try_div(Scalar(0)),
+            Err(ArithmeticError::DivByZero)
+        );
+        assert_eq!(
+            Scalar(i32::MIN).try_rem(Scalar(-1)),
+            Err(ArithmeticError::Overflow)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn test_div_by_zero() {
+        let _ = Scalar(1) / Scalar(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn test_rem_by_zero() {
+        let _ = Scalar(1) % Scalar(0);
+    }
+
+    #[test]
+    fn test_partial_eq_i32() {
+        assert!(Scalar(5) == 5);
+        assert!(Scalar(5) != 6);
+    }
+
+    #[test]
+    fn test_from_i32_for_each_scalar_instantiation() {
+        let narrow: Scalar<i32> = Scalar::from(7);
+        let wide: Scalar<i64> = Scalar::from(7);
+        assert_eq!(narrow.0, 7);
+        assert_eq!(wide.0, 7i64);
+    }
+
+    #[test]
+    fn test_area_through_trait_object() {
+        let shape: Box<dyn Shape> = Box::new(Point(2, -3));
+        assert_eq!(shape.area(), 6);
+        assert_eq!(Shape::area(&shape), 6);
+        assert!(!shape.is_degenerate());
+        assert!((&Point(0, 5) as &dyn Shape).is_degenerate());
+    }
+
+    #[test]
+    fn test_scalar_range() {
+        assert_eq!(
+            ScalarRange { current: 0, end: 3 }.collect::<Vec<_>>(),
+            [Scalar(0), Scalar(1), Scalar(2)]
+        );
+        assert_eq!(ScalarRange::new(5, 5).next(), None);
+        assert_eq!(ScalarRange::new(-1, 1).sum::<Scalar>(), Scalar(-1));
+    }
+
+    #[test]
+    fn test_raw_neg() {
+        let scalar = Scalar(5);
+        let ptr: *const Scalar = &scalar;
+        // SAFETY: `ptr` was just created from a live reference.
+        assert_eq!(unsafe { ptr.raw_neg() }, Scalar(-5));
+    }
+
+    #[test]
+    fn test_wrapping_and_saturating_mul() {
+        assert_eq!(
+            Wrapping(Scalar(i32::MAX)) * Wrapping(Scalar(2)),
+            Wrapping(Scalar(-2))
+        );
+        assert_eq!(
+            Saturating(Scalar(i32::MAX)) * Saturating(Scalar(2)),
+            Saturating(Scalar(i32::MAX))
+        );
+        assert_eq!(
+            Saturating(Scalar(i32::MIN)).combine(Scalar(2)),
+            Scalar(i32::MIN)
+        );
+        assert_eq!(Wrapping(Scalar(3)).combine(Scalar(4)), Scalar(12));
+    }
+
+    #[test]
+    fn test_mul_scalar_refs() {
+        assert_eq!(&Scalar(3) * &Scalar(4), Scalar(12));
+    }
+
+    #[test]
+    fn test_container_from_point_ref() {
+        let container = Container::<TypeA>::from(&Point(1, 2));
+        assert!(matches!(container.value, TypeA));
+    }
+
+    #[test]
+    fn test_as_ref_and_as_mut() {
+        fn takes<T: AsRef<i32>>(t: T) -> i32 {
+            *t.as_ref()
+        }
+        assert_eq!(takes(Scalar(4)), 4);
+
+        let mut s = Scalar(4);
+        *s.as_mut() += 1;
+        assert_eq!(s, Scalar(5));
+    }
+
+    #[test]
+    fn test_geometry_vec2_neg() {
+        assert_eq!(-geometry::Vec2(1, -2), geometry::Vec2(-1, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to negate with overflow")]
+    fn test_geometry_vec2_neg_overflow() {
+        let _ = -geometry::Vec2(0, i32::MIN);
+    }
+
+    #[test]
+    fn test_binary_and_octal() {
+        assert_eq!(format!("{:b}", Scalar(5)), "101");
+        assert_eq!(format!("{:#o}", Scalar(8)), "0o10");
+        assert_eq!(format!("{:08b}", Scalar(5)), "00000101");
+    }
+
+    #[test]
+    fn test_mul_point_ref_by_owned_scalar() {
+        assert_eq!(&Point(2, 3) * Scalar(4), Point(8, 12));
+    }
+
+    #[test]
+    fn test_expected_symbol_count() {
+        assert_eq!(all_crate_impls().len(), expected_symbol_count());
+        let cases: Vec<u32> = METHODS_PER_CASE.iter().map(|&(case, _)| case).collect();
+        // Every Case listed in the crate docs has a row.
+        let docs = include_str!("lib.rs");
+        let documented = (1..)
+            .take_while(|case| docs.contains(&format!("\n//! {}. ", case)))
+            .count() as u32;
+        assert_eq!(cases, (1..=documented).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_point_ordering() {
+        let mut points = vec![Point(2, 1), Point(1, 5), Point(2, 0), Point(1, -1)];
+        points.sort();
+        assert_eq!(
+            points,
+            [Point(1, -1), Point(1, 5), Point(2, 0), Point(2, 1)]
+        );
+        assert!(Point(0, 9) < Point(1, 0));
+    }
+
+    #[test]
+    fn test_point_add_and_sub() {
+        assert_eq!(Point(1, 2) + Point(10, -20), Point(11, -18));
+        assert_eq!(Point(1, 2) - Point(10, -20), Point(-9, 22));
+        assert_eq!(Point(4, 5) + Point::ORIGIN, Point(4, 5));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_point_add_overflow() {
+        let _ = Point(0, i32::MAX) + Point(0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn test_point_sub_overflow() {
+        let _ = Point(i32::MIN, 0) - Point(1, 0);
+    }
+
+    #[test]
+    fn test_to_point() {
+        assert_eq!(Scalar(5).to_point(), Point(5, 5));
+    }
+
+    #[test]
+    fn test_neg_container() {
+        assert_eq!((-Container { value: Scalar(3) }).value, Scalar(-3));
+        assert_eq!((-Container { value: 7i64 }).value, -7);
+    }
+
+    #[test]
+    fn test_try_from_scalar_for_sign() {
+        assert_eq!(Sign::try_from(&Scalar(3)), Ok(Sign::Positive));
+        assert_eq!(Sign::try_from(&Scalar(-3)), Ok(Sign::Negative));
+        assert_eq!(Sign::try_from(&Scalar(0)), Err(SignError));
+        assert_eq!(SignError.to_string(), "zero has no sign");
+    }
+
+    #[test]
+    fn test_mul_scalar_by_containers() {
+        let a = Scalar(2) * Container { value: TypeA };
+        let b = Scalar(2) * Container { value: TypeB };
+        assert!(matches!(a.value, TypeA));
+        assert!(matches!(b.value, TypeB));
+    }
+}
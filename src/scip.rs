@@ -0,0 +1,660 @@
+//! A hand-rolled subset of the SCIP index format.
+//!
+//! The types mirror the messages in `scip.proto` (and the `scip` crate's
+//! `scip::types`), but only carry the fields this crate reads or writes.
+//! Unknown fields are skipped when decoding, so indexes produced by
+//! rust-analyzer or verus-analyzer can be read directly.
+
+mod wire;
+
+pub use wire::DecodeError;
+
+use std::collections::BTreeMap;
+
+use crate::symbols::{self, SymbolFormat, DEFAULT_FORMAT};
+use wire::{Message, Value};
+
+/// `SymbolRole.Definition`.
+pub const SYMBOL_ROLE_DEFINITION: i32 = 1;
+
+/// `SymbolInformation.Kind.Method`.
+pub const SYMBOL_KIND_METHOD: i32 = 26;
+
+/// `SymbolInformation.Kind.StaticMethod`, used for methods without a receiver.
+pub const SYMBOL_KIND_STATIC_METHOD: i32 = 80;
+
+/// The document every expected symbol is attributed to.
+const DOCUMENT_PATH: &str = "src/lib.rs";
+
+/// A complete SCIP index.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Index {
+    pub metadata: Option<Metadata>,
+    pub documents: Vec<Document>,
+    pub external_symbols: Vec<SymbolInformation>,
+}
+
+/// Information about the tool that produced an index.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Metadata {
+    pub version: i32,
+    pub tool_info: Option<ToolInfo>,
+    pub project_root: String,
+    pub text_document_encoding: i32,
+}
+
+/// The name and version of an indexer.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ToolInfo {
+    pub name: String,
+    pub version: String,
+    pub arguments: Vec<String>,
+}
+
+/// The symbols and occurrences of a single source file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Document {
+    pub language: String,
+    pub relative_path: String,
+    pub occurrences: Vec<Occurrence>,
+    pub symbols: Vec<SymbolInformation>,
+}
+
+/// A reference to or definition of a symbol at a source range.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Occurrence {
+    /// `[start_line, start_char, end_char]` or
+    /// `[start_line, start_char, end_line, end_char]`.
+    pub range: Vec<i32>,
+    pub symbol: String,
+    pub symbol_roles: i32,
+}
+
+/// Metadata about a symbol defined in a document.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SymbolInformation {
+    pub symbol: String,
+    pub documentation: Vec<String>,
+    pub kind: i32,
+    pub display_name: String,
+}
+
+impl Index {
+    /// Encodes the index in the protobuf wire format.
+    pub fn encode_to_vec(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode(&mut out);
+        out
+    }
+
+    /// Decodes an index from the protobuf wire format, e.g. an `index.scip` file.
+    pub fn decode(bytes: &[u8]) -> Result<Index, DecodeError> {
+        <Index as Message>::decode(bytes)
+    }
+}
+
+/// Builds the index this crate expects an analyzer using `format` to produce.
+///
+/// It contains a single `src/lib.rs` document with one `SymbolInformation` and
+/// one definition occurrence per impl method in [`crate::all_crate_impls`].
+/// Functions without a `self` receiver, such as `default` or `from`, have
+/// kind [`SYMBOL_KIND_STATIC_METHOD`] and all others [`SYMBOL_KIND_METHOD`].
+/// Ranges are stubbed out as `[0, 0, 0]`.
+pub fn to_scip_index(format: SymbolFormat) -> Index {
+    let mut document = Document {
+        language: "rust".to_string(),
+        relative_path: DOCUMENT_PATH.to_string(),
+        ..Document::default()
+    };
+    for descriptor in crate::all_crate_impls() {
+        let symbol = descriptor.to_symbol(format);
+        let kind = if symbols::has_receiver(&descriptor, include_str!("lib.rs")) {
+            SYMBOL_KIND_METHOD
+        } else {
+            SYMBOL_KIND_STATIC_METHOD
+        };
+        document.occurrences.push(Occurrence {
+            range: vec![0, 0, 0],
+            symbol: symbol.clone(),
+            symbol_roles: SYMBOL_ROLE_DEFINITION,
+        });
+        document.symbols.push(SymbolInformation {
+            symbol,
+            documentation: Vec::new(),
+            kind,
+            display_name: descriptor.method,
+        });
+    }
+    Index {
+        metadata: Some(Metadata {
+            version: 0,
+            tool_info: Some(ToolInfo {
+                name: env!("CARGO_PKG_NAME").to_string(),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                arguments: Vec::new(),
+            }),
+            project_root: String::new(),
+            text_document_encoding: 0,
+        }),
+        documents: vec![document],
+        external_symbols: Vec::new(),
+    }
+}
+
+/// Builds the expected index in [`DEFAULT_FORMAT`].
+pub fn default_scip_index() -> Index {
+    to_scip_index(DEFAULT_FORMAT)
+}
+
+/// A difference between the symbols this crate expects and those in an index.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Mismatch {
+    /// An expected symbol that no document in the index defines.
+    Missing(String),
+    /// A method symbol the index defines that this crate does not expect.
+    Extra(String),
+}
+
+/// Checks `index` against the symbols an analyzer using `format` should emit.
+///
+/// Symbols are compared by descriptor, so the `rust-analyzer cargo
+/// minimal-scip-issue 0.1.0` scheme and package prefix of a real index is
+/// ignored. Only symbols of kind `Method` or `StaticMethod` can be reported as
+/// [`Mismatch::Extra`], since the expected symbols only cover impl methods.
+/// Missing symbols are reported first, in catalog order, then extra symbols in
+/// index order.
+pub fn validate_against_scip(index: &Index, format: SymbolFormat) -> Vec<Mismatch> {
+    let mut expected: Vec<String> = Vec::new();
+    for descriptor in crate::all_crate_impls() {
+        let symbol = descriptor.to_symbol(format);
+        if !expected.contains(&symbol) {
+            expected.push(symbol);
+        }
+    }
+    let defined: Vec<&SymbolInformation> = index
+        .documents
+        .iter()
+        .flat_map(|document| &document.symbols)
+        .collect();
+
+    let mut mismatches: Vec<Mismatch> = expected
+        .iter()
+        .filter(|symbol| !defined.iter().any(|s| descriptor(&s.symbol) == *symbol))
+        .map(|symbol| Mismatch::Missing(symbol.clone()))
+        .collect();
+    for info in defined {
+        if !matches!(info.kind, SYMBOL_KIND_METHOD | SYMBOL_KIND_STATIC_METHOD) {
+            continue;
+        }
+        let symbol = descriptor(&info.symbol);
+        let extra = Mismatch::Extra(symbol.to_string());
+        if !expected.iter().any(|e| e == symbol) && !mismatches.contains(&extra) {
+            mismatches.push(extra);
+        }
+    }
+    mismatches
+}
+
+/// The symbol-level differences between two indexes, from [`compare_indexes`].
+///
+/// Every list is sorted by symbol.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IndexDiff {
+    /// Symbols that only the expected index mentions.
+    pub only_in_expected: Vec<String>,
+    /// Symbols that only the actual index mentions.
+    pub only_in_actual: Vec<String>,
+    /// Symbols both indexes mention, but with different occurrence counts.
+    pub count_mismatches: Vec<CountMismatch>,
+}
+
+/// A symbol that occurs a different number of times in two indexes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CountMismatch {
+    pub symbol: String,
+    /// Occurrences in the expected index.
+    pub expected: usize,
+    /// Occurrences in the actual index.
+    pub actual: usize,
+}
+
+impl IndexDiff {
+    /// Returns `true` if the two indexes agree on every symbol.
+    pub fn is_empty(&self) -> bool {
+        self.only_in_expected.is_empty()
+            && self.only_in_actual.is_empty()
+            && self.count_mismatches.is_empty()
+    }
+}
+
+/// Diffs the symbols of two indexes across all of their documents.
+///
+/// A symbol is mentioned by an index if any occurrence or `SymbolInformation`
+/// names it. Symbol strings are compared verbatim, so unlike
+/// [`validate_against_scip`] a package prefix is significant. Ranges, roles and
+/// document paths are ignored.
+pub fn compare_indexes(expected: &Index, actual: &Index) -> IndexDiff {
+    let expected = occurrence_counts(expected);
+    let actual = occurrence_counts(actual);
+    let mut diff = IndexDiff::default();
+    for (symbol, &count) in &expected {
+        match actual.get(symbol) {
+            None => diff.only_in_expected.push(symbol.to_string()),
+            Some(&other) if other != count => diff.count_mismatches.push(CountMismatch {
+                symbol: symbol.to_string(),
+                expected: count,
+                actual: other,
+            }),
+            Some(_) => {}
+        }
+    }
+    diff.only_in_actual = actual
+        .keys()
+        .filter(|symbol| !expected.contains_key(*symbol))
+        .map(|symbol| symbol.to_string())
+        .collect();
+    diff
+}
+
+/// Counts the occurrences of `symbol` across all documents of `index`.
+///
+/// Symbols are compared verbatim. On an index from verus-analyzer this reveals
+/// collisions: the Case 3 `Mul` impls share `Mul#mul().`, so the count for it
+/// covers the definitions and uses of both impls, where rust-analyzer splits
+/// them over two symbols.
+pub fn count_occurrences(index: &Index, symbol: &str) -> usize {
+    index
+        .documents
+        .iter()
+        .flat_map(|document| &document.occurrences)
+        .filter(|occurrence| occurrence.symbol == symbol)
+        .count()
+}
+
+/// Counts the occurrences of every symbol `index` mentions, including symbols
+/// with a `SymbolInformation` but no occurrences.
+fn occurrence_counts(index: &Index) -> BTreeMap<&str, usize> {
+    let mut counts = BTreeMap::new();
+    for document in &index.documents {
+        for info in &document.symbols {
+            counts.entry(info.symbol.as_str()).or_insert(0);
+        }
+        for occurrence in &document.occurrences {
+            *counts.entry(occurrence.symbol.as_str()).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+/// Strips the `<scheme> <manager> <name> <version> ` prefix from a global
+/// symbol, leaving bare descriptors (as produced by [`to_scip_index`]) alone.
+///
+/// The scheme never contains `#`, whereas the first space-separated word of a
+/// bare descriptor always does, e.g. ``impl#[Point][`From<(i32,``.
+fn descriptor(symbol: &str) -> &str {
+    let mut parts = symbol.splitn(5, ' ');
+    match (parts.next(), parts.nth(3)) {
+        (Some(scheme), Some(rest)) if !scheme.contains('#') => rest,
+        _ => symbol,
+    }
+}
+
+fn invalid(value: &Value<'_>) -> DecodeError {
+    match value {
+        Value::Varint(_) => DecodeError::InvalidWireType(0),
+        Value::Bytes(_) => DecodeError::InvalidWireType(2),
+    }
+}
+
+fn string(value: &Value<'_>) -> Result<String, DecodeError> {
+    value.as_string().ok_or_else(|| invalid(value))?
+}
+
+fn int32(value: &Value<'_>) -> Result<i32, DecodeError> {
+    value.as_i32().ok_or_else(|| invalid(value))
+}
+
+impl Message for Index {
+    fn encode(&self, out: &mut Vec<u8>) {
+        if let Some(metadata) = &self.metadata {
+            wire::write_message(out, 1, metadata);
+        }
+        for document in &self.documents {
+            wire::write_message(out, 2, document);
+        }
+        for symbol in &self.external_symbols {
+            wire::write_message(out, 3, symbol);
+        }
+    }
+
+    fn merge_field(&mut self, field: u32, value: Value<'_>) -> Result<(), DecodeError> {
+        match field {
+            1 => self.metadata = Some(Metadata::decode_value(&value)?),
+            2 => self.documents.push(Document::decode_value(&value)?),
+            3 => self
+                .external_symbols
+                .push(SymbolInformation::decode_value(&value)?),
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl Message for Metadata {
+    fn encode(&self, out: &mut Vec<u8>) {
+        wire::write_i32(out, 1, self.version);
+        if let Some(tool_info) = &self.tool_info {
+            wire::write_message(out, 2, tool_info);
+        }
+        wire::write_string(out, 3, &self.project_root);
+        wire::write_i32(out, 4, self.text_document_encoding);
+    }
+
+    fn merge_field(&mut self, field: u32, value: Value<'_>) -> Result<(), DecodeError> {
+        match field {
+            1 => self.version = int32(&value)?,
+            2 => self.tool_info = Some(ToolInfo::decode_value(&value)?),
+            3 => self.project_root = string(&value)?,
+            4 => self.text_document_encoding = int32(&value)?,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl Message for ToolInfo {
+    fn encode(&self, out: &mut Vec<u8>) {
+        wire::write_string(out, 1, &self.name);
+        wire::write_string(out, 2, &self.version);
+        wire::write_strings(out, 3, &self.arguments);
+    }
+
+    fn merge_field(&mut self, field: u32, value: Value<'_>) -> Result<(), DecodeError> {
+        match field {
+            1 => self.name = string(&value)?,
+            2 => self.version = string(&value)?,
+            3 => self.arguments.push(string(&value)?),
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl Message for Document {
+    fn encode(&self, out: &mut Vec<u8>) {
+        wire::write_string(out, 1, &self.relative_path);
+        for occurrence in &self.occurrences {
+            wire::write_message(out, 2, occurrence);
+        }
+        for symbol in &self.symbols {
+            wire::write_message(out, 3, symbol);
+        }
+        wire::write_string(out, 4, &self.language);
+    }
+
+    fn merge_field(&mut self, field: u32, value: Value<'_>) -> Result<(), DecodeError> {
+        match field {
+            1 => self.relative_path = string(&value)?,
+            2 => self.occurrences.push(Occurrence::decode_value(&value)?),
+            3 => self.symbols.push(SymbolInformation::decode_value(&value)?),
+            4 => self.language = string(&value)?,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl Message for Occurrence {
+    fn encode(&self, out: &mut Vec<u8>) {
+        wire::write_packed_i32s(out, 1, &self.range);
+        wire::write_string(out, 2, &self.symbol);
+        wire::write_i32(out, 3, self.symbol_roles);
+    }
+
+    fn merge_field(&mut self, field: u32, value: Value<'_>) -> Result<(), DecodeError> {
+        match field {
+            1 => value.push_i32s(&mut self.range)?,
+            2 => self.symbol = string(&value)?,
+            3 => self.symbol_roles = int32(&value)?,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl Message for SymbolInformation {
+    fn encode(&self, out: &mut Vec<u8>) {
+        wire::write_string(out, 1, &self.symbol);
+        wire::write_strings(out, 3, &self.documentation);
+        wire::write_i32(out, 5, self.kind);
+        wire::write_string(out, 6, &self.display_name);
+    }
+
+    fn merge_field(&mut self, field: u32, value: Value<'_>) -> Result<(), DecodeError> {
+        match field {
+            1 => self.symbol = string(&value)?,
+            3 => self.documentation.push(string(&value)?),
+            5 => self.kind = int32(&value)?,
+            6 => self.display_name = string(&value)?,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_scip_index_symbol_count() {
+        for format in SymbolFormat::ALL {
+            let bytes = to_scip_index(format).encode_to_vec();
+            let index = Index::decode(&bytes).unwrap();
+            assert_eq!(index.documents.len(), 1);
+            assert_eq!(
+                index.documents[0].symbols.len(),
+                crate::all_crate_impls().len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_scip_index_symbol_kinds() {
+        let index = to_scip_index(SymbolFormat::RustAnalyzer);
+        let kind = |symbol: &str| {
+            index.documents[0]
+                .symbols
+                .iter()
+                .find(|info| info.symbol == symbol)
+                .unwrap_or_else(|| panic!("missing {}", symbol))
+                .kind
+        };
+        for symbol in [
+            "impl#[Scalar][Default]default().",
+            "impl#[Scalar][`From<i32>`]from().",
+            "impl#[Scalar][`TryFrom<i64>`]try_from().",
+            "impl#[Scalar][FromStr]from_str().",
+        ] {
+            assert_eq!(kind(symbol), SYMBOL_KIND_STATIC_METHOD, "{}", symbol);
+        }
+        for symbol in ["impl#[Scalar][Neg]neg().", "impl#[Scalar][Display]fmt()."] {
+            assert_eq!(kind(symbol), SYMBOL_KIND_METHOD, "{}", symbol);
+        }
+    }
+
+    #[test]
+    fn test_default_scip_index_uses_default_format() {
+        assert_eq!(default_scip_index(), to_scip_index(DEFAULT_FORMAT));
+    }
+
+    #[test]
+    fn test_validate_against_own_index() {
+        for format in SymbolFormat::ALL {
+            assert_eq!(validate_against_scip(&to_scip_index(format), format), []);
+        }
+    }
+
+    #[test]
+    fn test_validate_against_corrupted_index() {
+        let mut index = to_scip_index(SymbolFormat::RustAnalyzer);
+        let symbols = &mut index.documents[0].symbols;
+        let removed = symbols.remove(0).symbol;
+        symbols.push(SymbolInformation {
+            symbol: "impl#[Scalar][Bogus]bogus().".to_string(),
+            kind: SYMBOL_KIND_METHOD,
+            ..SymbolInformation::default()
+        });
+        symbols.push(SymbolInformation {
+            symbol: "Scalar#".to_string(),
+            kind: 49,
+            ..SymbolInformation::default()
+        });
+        assert_eq!(
+            validate_against_scip(&index, SymbolFormat::RustAnalyzer),
+            [
+                Mismatch::Missing(removed),
+                Mismatch::Extra("impl#[Scalar][Bogus]bogus().".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_against_rust_analyzer_index() {
+        let index = Index::decode(include_bytes!("../index-ra.scip")).unwrap();
+        let mismatches = validate_against_scip(&index, SymbolFormat::RustAnalyzer);
+        assert!(!mismatches.is_empty());
+        assert!(mismatches.iter().all(|m| matches!(m, Mismatch::Missing(_))));
+        assert!(!mismatches.contains(&Mismatch::Missing(
+            "impl#[`&Scalar`][Neg]neg().".to_string()
+        )));
+    }
+
+    #[test]
+    fn test_compare_index_with_itself() {
+        let index = to_scip_index(SymbolFormat::RustAnalyzer);
+        assert!(compare_indexes(&index, &index).is_empty());
+    }
+
+    #[test]
+    fn test_compare_indexes_pinpoints_mutation() {
+        let expected = to_scip_index(SymbolFormat::RustAnalyzer);
+        let mut actual = expected.clone();
+        let document = &mut actual.documents[0];
+        let removed = document.symbols.remove(0).symbol;
+        document.occurrences.retain(|o| o.symbol != removed);
+        let duplicated = document.occurrences[0].clone();
+        document.occurrences.push(Occurrence {
+            range: vec![7, 4, 9],
+            ..duplicated.clone()
+        });
+        document.occurrences.push(Occurrence {
+            range: vec![1, 2, 3],
+            symbol: "impl#[Scalar][Bogus]bogus().".to_string(),
+            symbol_roles: SYMBOL_ROLE_DEFINITION,
+        });
+        for occurrence in &mut document.occurrences {
+            occurrence.range = vec![42, 0, 1];
+        }
+
+        let diff = compare_indexes(&expected, &actual);
+        assert_eq!(diff.only_in_expected, [removed]);
+        assert_eq!(diff.only_in_actual, ["impl#[Scalar][Bogus]bogus()."]);
+        let expected_count = expected.documents[0]
+            .occurrences
+            .iter()
+            .filter(|o| o.symbol == duplicated.symbol)
+            .count();
+        assert_eq!(
+            diff.count_mismatches,
+            [CountMismatch {
+                symbol: duplicated.symbol,
+                expected: expected_count,
+                actual: expected_count + 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_count_occurrences_of_colliding_symbol() {
+        let occurrence = |line: i32| Occurrence {
+            range: vec![line, 0, 3],
+            symbol: "Mul#mul().".to_string(),
+            symbol_roles: SYMBOL_ROLE_DEFINITION,
+        };
+        let index = Index {
+            documents: vec![Document {
+                relative_path: DOCUMENT_PATH.to_string(),
+                occurrences: vec![occurrence(130), occurrence(145)],
+                ..Document::default()
+            }],
+            ..Index::default()
+        };
+        assert_eq!(count_occurrences(&index, "Mul#mul()."), 2);
+        assert_eq!(count_occurrences(&index, "Neg#neg()."), 0);
+
+        // One definition per impl sharing the symbol in the generated index.
+        let collided = count_occurrences(&to_scip_index(SymbolFormat::VerusAnalyzer), "Mul#mul().");
+        let table = crate::symbols::symbol_table(SymbolFormat::VerusAnalyzer);
+        assert_eq!(collided, table["Mul#mul()."].len());
+        let index = to_scip_index(SymbolFormat::RustAnalyzer);
+        assert_eq!(
+            count_occurrences(&index, "impl#[`&Point`][`Mul<&Scalar>`]mul()."),
+            1
+        );
+    }
+
+    #[test]
+    fn test_descriptor_strips_package_prefix() {
+        assert_eq!(
+            descriptor("rust-analyzer cargo minimal-scip-issue 0.1.0 impl#[Scalar][Neg]neg()."),
+            "impl#[Scalar][Neg]neg()."
+        );
+        assert_eq!(
+            descriptor("impl#[Point][`From<(i32, i32)>`]from()."),
+            "impl#[Point][`From<(i32, i32)>`]from()."
+        );
+    }
+
+    #[test]
+    fn test_encode_decode_round_trip() {
+        let index = to_scip_index(SymbolFormat::VerusAnalyzer);
+        assert_eq!(Index::decode(&index.encode_to_vec()), Ok(index));
+    }
+
+    #[test]
+    fn test_symbols_match_to_symbol() {
+        let index = to_scip_index(SymbolFormat::RustAnalyzer);
+        let symbols: Vec<&str> = index.documents[0]
+            .symbols
+            .iter()
+            .map(|s| s.symbol.as_str())
+            .collect();
+        let expected: Vec<String> = crate::all_crate_impls()
+            .iter()
+            .map(|d| d.to_symbol(SymbolFormat::RustAnalyzer))
+            .collect();
+        assert_eq!(symbols, expected);
+    }
+
+    #[test]
+    fn test_decode_rust_analyzer_index() {
+        let bytes = include_bytes!("../index-ra.scip");
+        let index = Index::decode(bytes).unwrap();
+        let tool_info = index.metadata.unwrap().tool_info.unwrap();
+        assert_eq!(tool_info.name, "rust-analyzer");
+        let document = &index.documents[0];
+        assert_eq!(document.relative_path, "src/lib.rs");
+        assert!(document.symbols.iter().any(|s| s.symbol
+            == "rust-analyzer cargo minimal-scip-issue 0.1.0 impl#[`&Scalar`][Neg]neg()."));
+    }
+
+    #[test]
+    fn test_decode_truncated_buffer() {
+        let bytes = to_scip_index(SymbolFormat::RustAnalyzer).encode_to_vec();
+        assert_eq!(
+            Index::decode(&bytes[..bytes.len() - 1]),
+            Err(DecodeError::UnexpectedEof)
+        );
+    }
+}
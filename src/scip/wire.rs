@@ -0,0 +1,227 @@
+//! A minimal protobuf wire-format encoder and decoder.
+//!
+//! Only the pieces needed for the SCIP messages are implemented: varints,
+//! length-delimited fields, and skipping of fixed-width fields.
+
+use std::fmt;
+
+/// The reasons a protobuf buffer can fail to decode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The buffer ended in the middle of a field.
+    UnexpectedEof,
+    /// A varint was longer than ten bytes.
+    VarintOverflow,
+    /// A string field was not valid UTF-8.
+    InvalidUtf8,
+    /// A field used a wire type that is not supported.
+    InvalidWireType(u8),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::UnexpectedEof => write!(f, "unexpected end of buffer"),
+            DecodeError::VarintOverflow => write!(f, "varint is too long"),
+            DecodeError::InvalidUtf8 => write!(f, "string field is not valid UTF-8"),
+            DecodeError::InvalidWireType(wire_type) => {
+                write!(f, "unsupported wire type {}", wire_type)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// A decoded field value.
+pub(crate) enum Value<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+}
+
+impl<'a> Value<'a> {
+    /// Interprets the value as an `int32`, or `None` for a length-delimited value.
+    pub(crate) fn as_i32(&self) -> Option<i32> {
+        match self {
+            Value::Varint(v) => Some(*v as i32),
+            Value::Bytes(_) => None,
+        }
+    }
+
+    /// Interprets the value as a string, or `None` for a varint.
+    pub(crate) fn as_string(&self) -> Option<Result<String, DecodeError>> {
+        match self {
+            Value::Varint(_) => None,
+            Value::Bytes(bytes) => Some(
+                std::str::from_utf8(bytes)
+                    .map(str::to_string)
+                    .map_err(|_| DecodeError::InvalidUtf8),
+            ),
+        }
+    }
+
+    /// Interprets the value as a repeated `int32`, accepting both packed and
+    /// unpacked encodings.
+    pub(crate) fn push_i32s(&self, out: &mut Vec<i32>) -> Result<(), DecodeError> {
+        match self {
+            Value::Varint(v) => out.push(*v as i32),
+            Value::Bytes(bytes) => {
+                let mut reader = Reader::new(bytes);
+                while !reader.is_empty() {
+                    out.push(reader.read_varint()? as i32);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A protobuf message that can be encoded and decoded.
+pub(crate) trait Message: Default {
+    /// Appends the encoded fields of this message to `out`.
+    fn encode(&self, out: &mut Vec<u8>);
+
+    /// Handles one decoded field. Unknown fields must be ignored.
+    fn merge_field(&mut self, field: u32, value: Value<'_>) -> Result<(), DecodeError>;
+
+    /// Decodes a message from its encoded fields.
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let mut message = Self::default();
+        let mut reader = Reader::new(bytes);
+        while let Some((field, value)) = reader.read_field()? {
+            message.merge_field(field, value)?;
+        }
+        Ok(message)
+    }
+
+    /// Decodes a message from a length-delimited field value.
+    fn decode_value(value: &Value<'_>) -> Result<Self, DecodeError> {
+        match value {
+            Value::Bytes(bytes) => Self::decode(bytes),
+            Value::Varint(_) => Err(DecodeError::InvalidWireType(0)),
+        }
+    }
+}
+
+/// Reads fields from an encoded message.
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Reader { buf, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.buf.len()
+    }
+
+    fn read_varint(&mut self) -> Result<u64, DecodeError> {
+        let mut value = 0u64;
+        for shift in (0..70).step_by(7) {
+            let byte = *self.buf.get(self.pos).ok_or(DecodeError::UnexpectedEof)?;
+            self.pos += 1;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(DecodeError::VarintOverflow)
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.buf.len())
+            .ok_or(DecodeError::UnexpectedEof)?;
+        let bytes = &self.buf[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    /// Reads the next varint or length-delimited field, skipping fixed-width ones.
+    fn read_field(&mut self) -> Result<Option<(u32, Value<'a>)>, DecodeError> {
+        while !self.is_empty() {
+            let key = self.read_varint()?;
+            let field = (key >> 3) as u32;
+            let wire_type = (key & 0x7) as u8;
+            match wire_type {
+                0 => return Ok(Some((field, Value::Varint(self.read_varint()?)))),
+                1 => {
+                    self.read_bytes(8)?;
+                }
+                2 => {
+                    let len = self.read_varint()? as usize;
+                    return Ok(Some((field, Value::Bytes(self.read_bytes(len)?))));
+                }
+                5 => {
+                    self.read_bytes(4)?;
+                }
+                other => return Err(DecodeError::InvalidWireType(other)),
+            }
+        }
+        Ok(None)
+    }
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn write_key(out: &mut Vec<u8>, field: u32, wire_type: u8) {
+    write_varint(out, (u64::from(field) << 3) | u64::from(wire_type));
+}
+
+fn write_len_delimited(out: &mut Vec<u8>, field: u32, bytes: &[u8]) {
+    write_key(out, field, 2);
+    write_varint(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+/// Writes an `int32` field, omitting the default value.
+pub(crate) fn write_i32(out: &mut Vec<u8>, field: u32, value: i32) {
+    if value != 0 {
+        write_key(out, field, 0);
+        // Negative int32 values are sign-extended to ten bytes.
+        write_varint(out, value as i64 as u64);
+    }
+}
+
+/// Writes a `string` field, omitting the default value.
+pub(crate) fn write_string(out: &mut Vec<u8>, field: u32, value: &str) {
+    if !value.is_empty() {
+        write_len_delimited(out, field, value.as_bytes());
+    }
+}
+
+/// Writes each element of a `repeated string` field.
+pub(crate) fn write_strings(out: &mut Vec<u8>, field: u32, values: &[String]) {
+    for value in values {
+        write_len_delimited(out, field, value.as_bytes());
+    }
+}
+
+/// Writes a packed `repeated int32` field.
+pub(crate) fn write_packed_i32s(out: &mut Vec<u8>, field: u32, values: &[i32]) {
+    if !values.is_empty() {
+        let mut packed = Vec::new();
+        for &value in values {
+            write_varint(&mut packed, value as i64 as u64);
+        }
+        write_len_delimited(out, field, &packed);
+    }
+}
+
+/// Writes an embedded message field.
+pub(crate) fn write_message<M: Message>(out: &mut Vec<u8>, field: u32, message: &M) {
+    let mut bytes = Vec::new();
+    message.encode(&mut bytes);
+    write_len_delimited(out, field, &bytes);
+}
@@ -0,0 +1,2195 @@
+//! Programmatic construction of the expected SCIP symbols.
+//!
+//! The doc comments in the crate root list the symbols each analyzer emits
+//! for every impl. The functions here build the same strings, so tests can
+//! check the documented expectations against a single source of truth.
+//!
+//! Only the descriptor part of a symbol is produced, i.e. everything after
+//! the `rust-analyzer cargo minimal-scip-issue 0.1.0 ` scheme/package prefix.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+mod json;
+pub mod moniker;
+pub mod verus_quirks;
+
+pub use json::JsonError;
+
+/// Primitive types, which are never ADTs and so never named by verus-analyzer.
+const PRIMITIVES: &[&str] = &[
+    "bool", "char", "str", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64",
+    "u128", "usize", "f32", "f64",
+];
+
+/// The symbol style emitted by a SCIP indexer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SymbolFormat {
+    /// `impl#[Self][Trait]method().`
+    RustAnalyzer,
+    /// `Self#Trait#method().`, with the quirks of verus-analyzer.
+    VerusAnalyzer,
+}
+
+/// The format used when none is given explicitly.
+///
+/// This is [`SymbolFormat::VerusAnalyzer`] when the `verus` feature is enabled
+/// and [`SymbolFormat::RustAnalyzer`] otherwise.
+#[cfg(feature = "verus")]
+pub const DEFAULT_FORMAT: SymbolFormat = SymbolFormat::VerusAnalyzer;
+
+/// The format used when none is given explicitly.
+///
+/// This is [`SymbolFormat::VerusAnalyzer`] when the `verus` feature is enabled
+/// and [`SymbolFormat::RustAnalyzer`] otherwise.
+#[cfg(not(feature = "verus"))]
+pub const DEFAULT_FORMAT: SymbolFormat = SymbolFormat::RustAnalyzer;
+
+impl Default for SymbolFormat {
+    fn default() -> Self {
+        DEFAULT_FORMAT
+    }
+}
+
+impl SymbolFormat {
+    /// Every format, for callers that want to compare the outputs.
+    pub const ALL: [SymbolFormat; 2] = [SymbolFormat::RustAnalyzer, SymbolFormat::VerusAnalyzer];
+
+    /// The name of the indexer, as in its SCIP symbols' scheme and `ToolInfo`.
+    pub fn tool_name(&self) -> &'static str {
+        match self {
+            SymbolFormat::RustAnalyzer => "rust-analyzer",
+            SymbolFormat::VerusAnalyzer => "verus-analyzer",
+        }
+    }
+
+    /// Builds the symbol for a method of a trait impl in this format.
+    pub fn format_impl(&self, self_ty: &str, trait_ty: &str, method: &str) -> String {
+        match self {
+            SymbolFormat::RustAnalyzer => {
+                rust_analyzer_trait_impl_symbol(self_ty, trait_ty, method)
+            }
+            SymbolFormat::VerusAnalyzer => {
+                verus_analyzer_trait_impl_symbol(self_ty, trait_ty, method)
+            }
+        }
+    }
+
+    /// Builds the symbol for a method of an inherent impl in this format.
+    pub fn format_inherent(&self, self_ty: &str, method: &str) -> String {
+        match self {
+            SymbolFormat::RustAnalyzer => format!("impl#[{}]{}().", escape_type(self_ty), method),
+            SymbolFormat::VerusAnalyzer => match adt_name(self_ty) {
+                Some(adt) => format!("{}#{}().", adt, method),
+                None => format!("{}().", method),
+            },
+        }
+    }
+}
+
+/// A method of an impl block, described as data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImplDescriptor {
+    /// The Self type, e.g. `&Scalar` or `Container<TypeA>`.
+    pub self_ty: String,
+    /// The trait name without generic arguments, or `None` for an inherent impl.
+    pub trait_ty: Option<String>,
+    /// The generic arguments of the trait, e.g. `["&Scalar"]` for `Mul<&Scalar>`.
+    pub trait_args: Vec<String>,
+    /// The method name.
+    pub method: String,
+    /// The 1-based line of the impl header in `src/lib.rs`, or 0 if unknown.
+    pub source_line: u32,
+    /// The path of the module containing the impl, relative to the crate root,
+    /// e.g. `geometry`. Empty for impls at the crate root.
+    pub module: String,
+}
+
+impl ImplDescriptor {
+    /// Creates a descriptor. Pass `None` as `trait_ty` for an inherent impl.
+    pub fn new(self_ty: &str, trait_ty: Option<&str>, trait_args: &[&str], method: &str) -> Self {
+        ImplDescriptor {
+            self_ty: self_ty.to_string(),
+            trait_ty: trait_ty.map(str::to_string),
+            trait_args: trait_args.iter().map(|arg| arg.to_string()).collect(),
+            method: method.to_string(),
+            source_line: 0,
+            module: String::new(),
+        }
+    }
+
+    /// Creates a descriptor for a method of an inherent impl.
+    pub fn inherent(self_ty: &str, method: &str) -> Self {
+        ImplDescriptor::new(self_ty, None, &[], method)
+    }
+
+    /// Places the impl in a module given by its path from the crate root,
+    /// e.g. `geometry` or `geometry::shapes`.
+    pub fn in_module(self, module: &str) -> Self {
+        ImplDescriptor {
+            module: module.to_string(),
+            ..self
+        }
+    }
+
+    /// The implemented trait with its arguments, or `None` for an inherent impl.
+    pub fn trait_ref(&self) -> Option<TraitRef> {
+        self.trait_ty.as_ref().map(|name| TraitRef {
+            name: name.clone(),
+            args: self.trait_args.clone(),
+        })
+    }
+
+    /// Builds the symbol of this impl method in the given format.
+    ///
+    /// Use [`write_symbol`] to reuse a buffer across many impls instead.
+    pub fn to_symbol(&self, format: SymbolFormat) -> String {
+        let mut symbol = String::new();
+        write_symbol(self, format, &mut symbol).expect("writing to a String cannot fail");
+        symbol
+    }
+}
+
+/// Writes the symbol of an impl method in the given format to `out`.
+///
+/// This produces the same text as [`ImplDescriptor::to_symbol`] without
+/// allocating.
+pub fn write_symbol<W: fmt::Write>(
+    descriptor: &ImplDescriptor,
+    format: SymbolFormat,
+    out: &mut W,
+) -> fmt::Result {
+    let ImplDescriptor {
+        self_ty,
+        trait_ty,
+        trait_args,
+        method,
+        module,
+        ..
+    } = descriptor;
+    // Both tools start the descriptor with a namespace per enclosing module.
+    if !module.is_empty() {
+        for segment in module.split("::") {
+            write!(out, "{}/", segment)?;
+        }
+    }
+    match format {
+        SymbolFormat::RustAnalyzer => {
+            out.write_str("impl#[")?;
+            write_escaped(out, self_ty)?;
+            out.write_char(']')?;
+            if let Some(trait_ty) = trait_ty {
+                out.write_char('[')?;
+                if trait_args.is_empty() {
+                    write_escaped(out, trait_ty)?;
+                } else {
+                    // The `<` always calls for quoting, as in `escape_type`.
+                    out.write_char('`')?;
+                    write_trait_ref(out, trait_ty, trait_args)?;
+                    out.write_char('`')?;
+                }
+                out.write_char(']')?;
+            }
+        }
+        SymbolFormat::VerusAnalyzer => {
+            if let Some(adt) = adt_name(self_ty) {
+                write!(out, "{}#", adt)?;
+            }
+            if let Some(trait_ty) = trait_ty {
+                write!(out, "{}#", strip_generics(trait_ty))?;
+            }
+        }
+    }
+    write!(out, "{}().", method)
+}
+
+/// Renders the method as a Rust path, e.g. `<&Point as Mul<&Scalar>>::mul` or
+/// `Scalar::convert` for an inherent impl.
+impl fmt::Display for ImplDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let qualified;
+        let self_ty = if self.module.is_empty() {
+            &self.self_ty
+        } else {
+            qualified = format!("{}::{}", self.module, self.self_ty);
+            &qualified
+        };
+        match &self.trait_ty {
+            Some(trait_ty) => {
+                write!(f, "<{} as ", self_ty)?;
+                write_trait_ref(f, trait_ty, &self.trait_args)?;
+                write!(f, ">::{}", self.method)
+            }
+            None => write!(f, "{}::{}", self_ty, self.method),
+        }
+    }
+}
+
+/// A trait with its generic arguments, as named in an impl header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraitRef {
+    /// The trait name, e.g. `Mul`.
+    pub name: String,
+    /// The generic arguments, e.g. `["&Scalar"]`.
+    pub args: Vec<String>,
+}
+
+impl TraitRef {
+    /// Renders the reference as Rust source, e.g. `Mul<&Scalar>`, or just `Neg`
+    /// without arguments.
+    pub fn render(&self) -> String {
+        let mut rendered = String::new();
+        write_trait_ref(&mut rendered, &self.name, &self.args)
+            .expect("writing to a String cannot fail");
+        rendered
+    }
+}
+
+/// Writes `name<args>`, or just `name` if there are no arguments.
+///
+/// This is shared by [`TraitRef::render`], the rust-analyzer symbol and the
+/// [`ImplDescriptor`] `Display` impl.
+fn write_trait_ref<W: fmt::Write>(out: &mut W, name: &str, args: &[String]) -> fmt::Result {
+    out.write_str(name)?;
+    if args.is_empty() {
+        return Ok(());
+    }
+    for (idx, arg) in args.iter().enumerate() {
+        out.write_str(if idx == 0 { "<" } else { ", " })?;
+        out.write_str(arg)?;
+    }
+    out.write_char('>')
+}
+
+/// Builds a symbol step by step, as an alternative to [`ImplDescriptor::new`]'s
+/// positional arguments:
+///
+/// ```
+/// use minimal_scip_issue::symbols::SymbolBuilder;
+/// use minimal_scip_issue::SymbolFormat;
+///
+/// let symbol = SymbolBuilder::new()
+///     .self_type("&Point")
+///     .trait_name("Mul")
+///     .trait_arg("&Scalar")
+///     .method("mul")
+///     .build(SymbolFormat::RustAnalyzer);
+/// assert_eq!(symbol, "impl#[`&Point`][`Mul<&Scalar>`]mul().");
+/// ```
+///
+/// Without a `trait_name` the symbol is that of an inherent method.
+#[derive(Clone, Debug, Default)]
+pub struct SymbolBuilder {
+    self_ty: String,
+    trait_ty: Option<String>,
+    trait_args: Vec<String>,
+    method: Option<String>,
+}
+
+impl SymbolBuilder {
+    /// Starts a builder with no Self type, trait or method.
+    pub fn new() -> SymbolBuilder {
+        SymbolBuilder::default()
+    }
+
+    /// Sets the Self type, e.g. `&Scalar`.
+    pub fn self_type(mut self, self_ty: &str) -> SymbolBuilder {
+        self.self_ty = self_ty.to_string();
+        self
+    }
+
+    /// Sets the trait name, without generic arguments.
+    pub fn trait_name(mut self, trait_ty: &str) -> SymbolBuilder {
+        self.trait_ty = Some(trait_ty.to_string());
+        self
+    }
+
+    /// Appends a generic argument of the trait.
+    pub fn trait_arg(mut self, arg: &str) -> SymbolBuilder {
+        self.trait_args.push(arg.to_string());
+        self
+    }
+
+    /// Sets the method name.
+    pub fn method(mut self, method: &str) -> SymbolBuilder {
+        self.method = Some(method.to_string());
+        self
+    }
+
+    /// Builds the symbol in the given format.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`SymbolBuilder::method`] was never called.
+    pub fn build(&self, format: SymbolFormat) -> String {
+        let method = self
+            .method
+            .as_deref()
+            .expect("SymbolBuilder::build called without a method");
+        let args: Vec<&str> = self.trait_args.iter().map(String::as_str).collect();
+        ImplDescriptor::new(&self.self_ty, self.trait_ty.as_deref(), &args, method)
+            .to_symbol(format)
+    }
+}
+
+/// How a SCIP symbol relates to another, as in `scip.proto`'s `Relationship`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RelationshipKind {
+    /// The symbol implements the other one, e.g. an impl method and the trait
+    /// method it implements.
+    Implementation,
+    /// The symbol's occurrences also count as references to the other one.
+    Reference,
+    /// The other symbol is the type of this one.
+    TypeDefinition,
+}
+
+/// A relationship from one symbol to `symbol`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Relationship {
+    /// The related symbol. Unlike [`ImplDescriptor::to_symbol`], this is a
+    /// global symbol with its scheme and package, since the related symbol is
+    /// often defined in another package.
+    pub symbol: String,
+    pub kind: RelationshipKind,
+}
+
+/// The scheme and package of symbols defined in `core`, as rust-analyzer
+/// writes them in `index-ra.json`.
+const CORE_PACKAGE: &str =
+    "rust-analyzer cargo core https://github.com/rust-lang/rust/library/core";
+
+/// The module of each `core` trait implemented in this crate, as a descriptor
+/// namespace prefix.
+const CORE_TRAITS: &[(&str, &str)] = &[
+    ("Add", "ops/arith/"),
+    ("AddAssign", "ops/arith/"),
+    ("Div", "ops/arith/"),
+    ("Mul", "ops/arith/"),
+    ("MulAssign", "ops/arith/"),
+    ("Neg", "ops/arith/"),
+    ("Rem", "ops/arith/"),
+    ("Sub", "ops/arith/"),
+    ("Deref", "ops/deref/"),
+    ("Index", "ops/index/"),
+    ("IndexMut", "ops/index/"),
+    ("AsMut", "convert/"),
+    ("AsRef", "convert/"),
+    ("From", "convert/"),
+    ("TryFrom", "convert/"),
+    ("Binary", "fmt/"),
+    ("Display", "fmt/"),
+    ("Octal", "fmt/"),
+    ("Eq", "cmp/"),
+    ("Ord", "cmp/"),
+    ("PartialEq", "cmp/"),
+    ("PartialOrd", "cmp/"),
+    ("Clone", "clone/"),
+    ("Default", "default/"),
+    ("Hash", "hash/"),
+    ("FromStr", "str/traits/"),
+    ("Product", "iter/traits/accum/"),
+    ("Sum", "iter/traits/accum/"),
+    ("IntoIterator", "iter/traits/collect/"),
+    ("Iterator", "iter/traits/iterator/"),
+];
+
+/// Lists the relationships of an impl method's symbol.
+///
+/// A trait impl method implements the trait's method. For a `core` trait its
+/// symbol is in `core`'s package and module, e.g.
+/// `rust-analyzer cargo core https://github.com/rust-lang/rust/library/core ops/arith/Neg#neg().`
+/// for every `neg`; any other trait is one of this crate's, defined at the
+/// crate root. An inherent method has no relationships.
+///
+/// The package prefix keeps the target apart from impl symbols. A bare
+/// `Neg#neg().` would be exactly the verus-analyzer symbol of Case 2.
+pub fn relationships(descriptor: &ImplDescriptor) -> Vec<Relationship> {
+    let Some(trait_ty) = &descriptor.trait_ty else {
+        return Vec::new();
+    };
+    let trait_name = strip_generics(trait_ty);
+    let symbol = match CORE_TRAITS.iter().find(|(name, _)| *name == trait_name) {
+        Some((_, namespace)) => format!(
+            "{} {}{}#{}().",
+            CORE_PACKAGE, namespace, trait_name, descriptor.method
+        ),
+        None => format!(
+            "rust-analyzer cargo {} {} {}#{}().",
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION"),
+            trait_name,
+            descriptor.method
+        ),
+    };
+    vec![Relationship {
+        symbol,
+        kind: RelationshipKind::Implementation,
+    }]
+}
+
+/// Looks up the symbol of the crate impl method with the given Rust path, as
+/// rendered by [`ImplDescriptor`]'s `Display` impl, e.g. `<&Scalar as Neg>::neg`
+/// or `Scalar::checked_neg`.
+///
+/// Returns `None` if no impl in [`crate::all_crate_impls`] has that path.
+pub fn symbol_for_method(path: &str, format: SymbolFormat) -> Option<String> {
+    let path = path.trim();
+    crate::all_crate_impls()
+        .iter()
+        .find(|descriptor| descriptor.to_string() == path)
+        .map(|descriptor| descriptor.to_symbol(format))
+}
+
+/// Asserts that no two impl descriptors produce the same symbol in a format.
+///
+/// On failure, the panic message names every colliding symbol and the impls
+/// sharing it:
+///
+/// ```should_panic
+/// use minimal_scip_issue::{all_crate_impls, assert_unique_symbols, SymbolFormat};
+///
+/// assert_unique_symbols!(SymbolFormat::RustAnalyzer, all_crate_impls());
+/// assert_unique_symbols!(SymbolFormat::VerusAnalyzer, all_crate_impls()); // panics
+/// ```
+#[macro_export]
+macro_rules! assert_unique_symbols {
+    ($format:expr, $impls:expr $(,)?) => {{
+        let format: $crate::SymbolFormat = $format;
+        let impls: &[$crate::ImplDescriptor] = &$impls;
+        let collisions = $crate::symbols::find_duplicate_symbols(impls, format);
+        if !collisions.is_empty() {
+            let mut message =
+                ::std::format!("{} duplicate {:?} symbol(s):", collisions.len(), format);
+            for (symbol, members) in &collisions {
+                message.push_str(&::std::format!("\n  `{}` is shared by:", symbol));
+                for &idx in members {
+                    message.push_str(&::std::format!("\n    {}", impls[idx]));
+                }
+            }
+            ::std::panic!("{}", message);
+        }
+    }};
+}
+
+/// Writes single-method trait impls and a registry function describing them,
+/// so each impl is only spelled out once.
+///
+/// The invocation starts with the signature of the registry function, which
+/// returns the descriptors in the order the impls are written. Each impl may
+/// take type parameters with at most one bound each, and may declare
+/// associated types before its one method. Defaulted trait arguments must be
+/// written out (e.g. `Add<Self>`) for the descriptor to match the full trait
+/// reference.
+///
+/// ```
+/// use minimal_scip_issue::{describe_impl, SymbolFormat};
+///
+/// pub struct Meters(pub i32);
+/// pub struct Labeled<T>(pub T);
+///
+/// describe_impl! {
+///     fn meters_impls;
+///
+///     impl From<i32> for Meters {
+///         fn from(value: i32) -> Meters {
+///             Meters(value)
+///         }
+///     }
+/// }
+///
+/// describe_impl! {
+///     fn labeled_impls;
+///
+///     impl<T: Default> Default for Labeled<T> {
+///         fn default() -> Labeled<T> {
+///             Labeled(T::default())
+///         }
+///     }
+/// }
+///
+/// assert_eq!(
+///     meters_impls()[0].to_symbol(SymbolFormat::RustAnalyzer),
+///     "impl#[Meters][`From<i32>`]from()."
+/// );
+/// assert_eq!(
+///     labeled_impls()[0].to_symbol(SymbolFormat::RustAnalyzer),
+///     "impl#[`Labeled<T>`][Default]default()."
+/// );
+/// ```
+#[macro_export]
+macro_rules! describe_impl {
+    (
+        $vis:vis fn $registry:ident;
+        $(
+            $(#[$attr:meta])*
+            impl $(<$($param:ident $(: $bound:path)?),+>)?
+                $trait_ty:ident $(<$($arg:ty),+>)? for $self_ty:ty {
+                $(type $assoc:ident = $assoc_ty:ty;)*
+                fn $method:ident $($sig_and_body:tt)*
+            }
+        )+
+    ) => {
+        $(
+            $(#[$attr])*
+            impl $(<$($param $(: $bound)?),+>)? $trait_ty $(<$($arg),+>)? for $self_ty {
+                $(type $assoc = $assoc_ty;)*
+                fn $method $($sig_and_body)*
+            }
+        )+
+
+        /// Describes the impls written through this [`describe_impl!`] invocation.
+        $vis fn $registry() -> ::std::vec::Vec<$crate::ImplDescriptor> {
+            ::std::vec![$(
+                $crate::ImplDescriptor::new(
+                    ::std::stringify!($self_ty),
+                    ::std::option::Option::Some(::std::stringify!($trait_ty)),
+                    &[$($(::std::stringify!($arg)),+)?],
+                    ::std::stringify!($method),
+                )
+            ),+]
+        }
+    };
+}
+
+/// Sets the `source_line` of each impl from `source`, the text of `src/lib.rs`.
+///
+/// `impls` must be in source order. Each descriptor is matched to the next
+/// `fn` with its method name that is nested in an `impl` block (not a `trait`),
+/// and gets the line of that block's header. Scanning stops at `mod tests`.
+pub(crate) fn assign_source_lines(impls: &mut [ImplDescriptor], source: &str) {
+    let mut methods = Vec::new();
+    let mut header: Option<(usize, usize)> = None;
+    for (idx, line) in source.lines().enumerate() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        if trimmed.starts_with("mod tests") {
+            break;
+        }
+        if trimmed.starts_with("impl ") || trimmed.starts_with("impl<") {
+            header = Some((idx + 1, indent));
+        } else if trimmed.starts_with("pub trait ") || trimmed.starts_with("trait ") {
+            header = None;
+        } else if let Some(rest) = fn_name_start(trimmed) {
+            match header {
+                Some((line_no, header_indent)) if indent > header_indent => {
+                    let name = rest.split(['(', '<']).next().unwrap_or(rest);
+                    methods.push((name, line_no));
+                }
+                _ => header = None,
+            }
+        }
+    }
+    let mut methods = methods.into_iter();
+    for descriptor in impls {
+        if let Some((_, line_no)) = methods.find(|(name, _)| *name == descriptor.method) {
+            descriptor.source_line = line_no as u32;
+        }
+    }
+}
+
+/// Whether the impl method takes a `self` receiver, judged from its signature
+/// in `source`, the text of `src/lib.rs`.
+///
+/// The method is the first `fn` with its name after the impl header at the
+/// descriptor's `source_line`. Descriptors without a source line count as
+/// taking a receiver.
+pub(crate) fn has_receiver(descriptor: &ImplDescriptor, source: &str) -> bool {
+    let Some(start) = (descriptor.source_line as usize).checked_sub(1) else {
+        return true;
+    };
+    let lines: Vec<&str> = source.lines().skip(start).collect();
+    let Some(fn_line) = lines.iter().position(|line| {
+        fn_name_start(line.trim_start()).is_some_and(|rest| {
+            rest.strip_prefix(descriptor.method.as_str())
+                .is_some_and(|after| after.starts_with(['(', '<']))
+        })
+    }) else {
+        return true;
+    };
+    let signature = lines[fn_line..].join(" ");
+    let after_name =
+        &signature[signature.find(descriptor.method.as_str()).unwrap() + descriptor.method.len()..];
+    // Skip method-level generics, whose bounds may contain parentheses or `->`.
+    let mut depth = 0;
+    let mut prev = ' ';
+    let mut params = after_name;
+    for (idx, c) in after_name.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' if prev != '-' => depth -= 1,
+            '(' if depth == 0 => {
+                params = &after_name[idx + 1..];
+                break;
+            }
+            _ => {}
+        }
+        prev = c;
+    }
+    let first = params.split([',', ')']).next().unwrap_or("");
+    let binding = first.split(':').next().unwrap_or("");
+    binding
+        .split_whitespace()
+        .last()
+        .is_some_and(|word| word.trim_start_matches('&') == "self")
+}
+
+/// Returns the text after `fn ` if `line` starts a function, skipping any
+/// `pub`, `const` and `unsafe` qualifiers.
+fn fn_name_start(line: &str) -> Option<&str> {
+    let mut rest = line.strip_prefix("pub ").unwrap_or(line);
+    for qualifier in ["const ", "unsafe "] {
+        rest = rest.strip_prefix(qualifier).unwrap_or(rest);
+    }
+    rest.strip_prefix("fn ")
+}
+
+/// Describes every verus-analyzer collision in this crate, one line per symbol.
+///
+/// Each line names the shared symbol and the `src/lib.rs` lines of the impls
+/// producing it, e.g. `Duplicate 'Mul#mul().' at lines 101 and 116`.
+pub fn collision_report() -> String {
+    let impls = crate::all_crate_impls();
+    let mut report = String::new();
+    for (symbol, members) in find_duplicate_symbols(&impls, SymbolFormat::VerusAnalyzer) {
+        let lines: Vec<String> = members
+            .iter()
+            .map(|&idx| impls[idx].source_line.to_string())
+            .collect();
+        let (last, rest) = lines
+            .split_last()
+            .expect("groups have at least two members");
+        report.push_str(&format!(
+            "Duplicate '{}' at lines {} and {}\n",
+            symbol,
+            rest.join(", "),
+            last
+        ));
+    }
+    report
+}
+
+/// Renders the verus-analyzer collisions among `impls` as a Graphviz DOT graph.
+///
+/// Every impl is a node `n<index>` labeled with its Rust path, and every pair
+/// of impls sharing a verus-analyzer symbol is joined by an edge labeled with
+/// that symbol. Pipe the output through `dot -Tsvg` to draw it.
+pub fn to_dot(impls: &[ImplDescriptor]) -> String {
+    let mut dot = String::from("graph collisions {\n    node [shape=box];\n");
+    for (idx, descriptor) in impls.iter().enumerate() {
+        dot.push_str(&format!(
+            "    n{} [label={}];\n",
+            idx,
+            dot_string(&descriptor.to_string())
+        ));
+    }
+    for (symbol, members) in find_duplicate_symbols(impls, SymbolFormat::VerusAnalyzer) {
+        let label = dot_string(&symbol);
+        for (pos, &a) in members.iter().enumerate() {
+            for &b in &members[pos + 1..] {
+                dot.push_str(&format!("    n{} -- n{} [label={}];\n", a, b, label));
+            }
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Quotes `s` as a DOT string literal.
+fn dot_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Groups impls by their symbol and returns the groups with more than one member.
+///
+/// Each group holds the shared symbol and the indices of the colliding impls in
+/// `impls`. Groups are ordered by the first impl that produces them.
+pub fn find_duplicate_symbols(
+    impls: &[ImplDescriptor],
+    format: SymbolFormat,
+) -> Vec<(String, Vec<usize>)> {
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    let mut group_of: HashMap<String, usize> = HashMap::new();
+    for (idx, descriptor) in impls.iter().enumerate() {
+        let symbol = descriptor.to_symbol(format);
+        match group_of.get(&symbol) {
+            Some(&group) => groups[group].1.push(idx),
+            None => {
+                group_of.insert(symbol.clone(), groups.len());
+                groups.push((symbol, vec![idx]));
+            }
+        }
+    }
+    groups.retain(|(_, members)| members.len() > 1);
+    groups
+}
+
+/// Builds the rust-analyzer symbol for a method of a trait impl.
+///
+/// rust-analyzer names the impl block by its full Self type and its full trait
+/// reference, e.g. `impl#[Scalar][Neg]neg().`. Types containing `&`, angle
+/// brackets or whitespace are quoted with backticks, e.g.
+/// ``impl#[`&Scalar`][Neg]neg().``.
+pub fn rust_analyzer_trait_impl_symbol(self_ty: &str, trait_ty: &str, method: &str) -> String {
+    format!(
+        "impl#[{}][{}]{}().",
+        escape_type(self_ty),
+        escape_type(trait_ty),
+        method
+    )
+}
+
+/// Builds the verus-analyzer symbol for a method of a trait impl.
+///
+/// verus-analyzer only names the Self type when it is an ADT (so `&Scalar` is
+/// dropped entirely) and strips generic arguments from both the Self type and
+/// the trait, e.g. `Scalar#Neg#neg().`, `Neg#neg().` or `Container#From#from().`.
+pub fn verus_analyzer_trait_impl_symbol(self_ty: &str, trait_ty: &str, method: &str) -> String {
+    let mut symbol = String::new();
+    if let Some(adt) = adt_name(self_ty) {
+        symbol.push_str(adt);
+        symbol.push('#');
+    }
+    symbol.push_str(strip_generics(trait_ty));
+    symbol.push('#');
+    symbol.push_str(method);
+    symbol.push_str("().");
+    symbol
+}
+
+/// Serializes [`crate::all_crate_impls`] and their symbols in `format` as JSON.
+///
+/// The output has one impl per line, so catalogs from different versions of
+/// the crate or the analyzers diff cleanly:
+///
+/// ```text
+/// {
+///   "format": "rust-analyzer",
+///   "impls": [
+///     {"self_ty": "Scalar", "trait_ty": "Neg", "trait_args": [], "method": "neg", "source_line": 64, "module": "", "symbol": "impl#[Scalar][Neg]neg()."},
+///     ...
+///   ]
+/// }
+/// ```
+pub fn catalog_json(format: SymbolFormat) -> String {
+    let mut out = String::from("{\n  \"format\": ");
+    json::write_string(&mut out, format.tool_name());
+    out.push_str(",\n  \"impls\": [");
+    for (idx, descriptor) in crate::all_crate_impls().iter().enumerate() {
+        out.push_str(if idx == 0 { "\n    " } else { ",\n    " });
+        out.push_str("{\"self_ty\": ");
+        json::write_string(&mut out, &descriptor.self_ty);
+        out.push_str(", \"trait_ty\": ");
+        match &descriptor.trait_ty {
+            Some(trait_ty) => json::write_string(&mut out, trait_ty),
+            None => out.push_str("null"),
+        }
+        out.push_str(", \"trait_args\": [");
+        for (idx, arg) in descriptor.trait_args.iter().enumerate() {
+            if idx > 0 {
+                out.push_str(", ");
+            }
+            json::write_string(&mut out, arg);
+        }
+        out.push_str("], \"method\": ");
+        json::write_string(&mut out, &descriptor.method);
+        out.push_str(&format!(", \"source_line\": {}", descriptor.source_line));
+        out.push_str(", \"module\": ");
+        json::write_string(&mut out, &descriptor.module);
+        out.push_str(", \"symbol\": ");
+        json::write_string(&mut out, &descriptor.to_symbol(format));
+        out.push('}');
+    }
+    out.push_str("\n  ]\n}\n");
+    out
+}
+
+/// Serializes both symbols of every impl in `impls` and the verus-analyzer
+/// collision groups as JSON, mirroring the `scip-symbols` table.
+///
+/// Each impl is on its own line, with `duplicate` set if its verus-analyzer
+/// symbol is shared. Collision groups list indices into `impls`:
+///
+/// ```text
+/// {
+///   "impls": [
+///     {"path": "<Scalar as Neg>::neg", "rust_analyzer": "impl#[Scalar][Neg]neg().", "verus_analyzer": "Scalar#Neg#neg().", "duplicate": false},
+///     ...
+///   ],
+///   "collisions": [
+///     {"symbol": "Neg#neg().", "impls": [1, 53]},
+///     ...
+///   ]
+/// }
+/// ```
+pub fn symbol_report_json(impls: &[ImplDescriptor]) -> String {
+    let collisions = find_duplicate_symbols(impls, SymbolFormat::VerusAnalyzer);
+    let colliding: HashSet<usize> = collisions
+        .iter()
+        .flat_map(|(_, members)| members.iter().copied())
+        .collect();
+    let mut out = String::from("{\n  \"impls\": [");
+    for (idx, descriptor) in impls.iter().enumerate() {
+        out.push_str(if idx == 0 { "\n    " } else { ",\n    " });
+        out.push_str("{\"path\": ");
+        json::write_string(&mut out, &descriptor.to_string());
+        out.push_str(", \"rust_analyzer\": ");
+        json::write_string(&mut out, &descriptor.to_symbol(SymbolFormat::RustAnalyzer));
+        out.push_str(", \"verus_analyzer\": ");
+        json::write_string(&mut out, &descriptor.to_symbol(SymbolFormat::VerusAnalyzer));
+        out.push_str(&format!(", \"duplicate\": {}}}", colliding.contains(&idx)));
+    }
+    out.push_str("\n  ],\n  \"collisions\": [");
+    for (idx, (symbol, members)) in collisions.iter().enumerate() {
+        out.push_str(if idx == 0 { "\n    " } else { ",\n    " });
+        out.push_str("{\"symbol\": ");
+        json::write_string(&mut out, symbol);
+        let members: Vec<String> = members.iter().map(usize::to_string).collect();
+        out.push_str(&format!(", \"impls\": [{}]}}", members.join(", ")));
+    }
+    out.push_str("\n  ]\n}\n");
+    out
+}
+
+/// Parses a catalog written by [`catalog_json`] back into its format and impls.
+///
+/// The `symbol` fields are not read, since they are derived from the rest.
+pub fn parse_catalog_json(input: &str) -> Result<(SymbolFormat, Vec<ImplDescriptor>), JsonError> {
+    let root = json::parse(input)?;
+    let format = root
+        .get("format")
+        .and_then(json::Value::as_str)
+        .and_then(|name| {
+            SymbolFormat::ALL
+                .into_iter()
+                .find(|f| f.tool_name() == name)
+        })
+        .ok_or(JsonError::InvalidField("format"))?;
+    let impls = root
+        .get("impls")
+        .and_then(json::Value::as_array)
+        .ok_or(JsonError::InvalidField("impls"))?;
+    let string = |item: &json::Value, name: &'static str| {
+        item.get(name)
+            .and_then(json::Value::as_str)
+            .map(str::to_string)
+            .ok_or(JsonError::InvalidField(name))
+    };
+    let mut descriptors = Vec::new();
+    for item in impls {
+        let trait_ty = match item.get("trait_ty") {
+            Some(json::Value::Null) => None,
+            _ => Some(string(item, "trait_ty")?),
+        };
+        let trait_args = item
+            .get("trait_args")
+            .and_then(json::Value::as_array)
+            .and_then(|args| {
+                args.iter()
+                    .map(|arg| arg.as_str().map(str::to_string))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .ok_or(JsonError::InvalidField("trait_args"))?;
+        let source_line = item
+            .get("source_line")
+            .and_then(json::Value::as_u64)
+            .and_then(|line| u32::try_from(line).ok())
+            .ok_or(JsonError::InvalidField("source_line"))?;
+        descriptors.push(ImplDescriptor {
+            self_ty: string(item, "self_ty")?,
+            trait_ty,
+            trait_args,
+            method: string(item, "method")?,
+            source_line,
+            module: string(item, "module")?,
+        });
+    }
+    Ok((format, descriptors))
+}
+
+/// Summary counts of how far the two formats diverge for a set of impls.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SymbolStats {
+    /// The number of impls examined.
+    pub total_impls: usize,
+    /// The number of distinct rust-analyzer symbols.
+    pub unique_rust_analyzer: usize,
+    /// The number of distinct verus-analyzer symbols.
+    pub unique_verus_analyzer: usize,
+    /// The number of verus-analyzer symbols shared by more than one impl.
+    pub verus_collisions: usize,
+}
+
+/// Counts the distinct symbols and verus-analyzer collisions among `impls`.
+pub fn symbol_stats(impls: &[ImplDescriptor]) -> SymbolStats {
+    let unique = |format| {
+        impls
+            .iter()
+            .map(|d| d.to_symbol(format))
+            .collect::<HashSet<_>>()
+            .len()
+    };
+    SymbolStats {
+        total_impls: impls.len(),
+        unique_rust_analyzer: unique(SymbolFormat::RustAnalyzer),
+        unique_verus_analyzer: unique(SymbolFormat::VerusAnalyzer),
+        verus_collisions: find_duplicate_symbols(impls, SymbolFormat::VerusAnalyzer).len(),
+    }
+}
+
+/// Counts the crate's impl symbols in `format` by length in bytes.
+///
+/// verus-analyzer symbols come out shorter across the board, since they drop
+/// the Self types and trait arguments rust-analyzer spells out.
+pub fn symbol_length_histogram(format: SymbolFormat) -> BTreeMap<usize, usize> {
+    let mut histogram = BTreeMap::new();
+    for descriptor in crate::all_crate_impls() {
+        *histogram
+            .entry(descriptor.to_symbol(format).len())
+            .or_insert(0) += 1;
+    }
+    histogram
+}
+
+/// Maps every symbol of the crate's impls to the impls that produce it.
+///
+/// Any key with more than one descriptor is a collision.
+pub fn symbol_table(format: SymbolFormat) -> BTreeMap<String, Vec<ImplDescriptor>> {
+    let mut table: BTreeMap<String, Vec<ImplDescriptor>> = BTreeMap::new();
+    for descriptor in crate::all_crate_impls() {
+        table
+            .entry(descriptor.to_symbol(format))
+            .or_default()
+            .push(descriptor);
+    }
+    table
+}
+
+/// Makes a colliding symbol unique by appending the impl's position.
+///
+/// `impl_index` is the zero-based position of the impl among the
+/// `total_with_symbol` impls sharing `symbol`, in source order, so the two
+/// Case 3 impls become `Mul#mul().#1` and `Mul#mul().#2`. Symbols that are
+/// already unique (`total_with_symbol <= 1`) are returned unchanged.
+pub fn disambiguate(symbol: &str, impl_index: usize, total_with_symbol: usize) -> String {
+    if total_with_symbol <= 1 {
+        symbol.to_string()
+    } else {
+        format!("{}#{}", symbol, impl_index + 1)
+    }
+}
+
+/// How one impl's symbol differs between the two formats.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormatDiff {
+    /// The impl being compared.
+    pub descriptor: ImplDescriptor,
+    /// Its rust-analyzer symbol.
+    pub rust_analyzer: String,
+    /// Its verus-analyzer symbol.
+    pub verus_analyzer: String,
+    /// Whether verus-analyzer omits the Self type entirely, as in Case 2.
+    pub self_type_dropped: bool,
+}
+
+/// Compares the rust-analyzer and verus-analyzer symbols of each impl.
+pub fn diff_formats(impls: &[ImplDescriptor]) -> Vec<FormatDiff> {
+    impls
+        .iter()
+        .map(|descriptor| FormatDiff {
+            descriptor: descriptor.clone(),
+            rust_analyzer: descriptor.to_symbol(SymbolFormat::RustAnalyzer),
+            verus_analyzer: descriptor.to_symbol(SymbolFormat::VerusAnalyzer),
+            self_type_dropped: adt_name(&descriptor.self_ty).is_none(),
+        })
+        .collect()
+}
+
+/// The components of a rust-analyzer impl method symbol.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParsedSymbol {
+    /// The Self type, with any backtick quoting removed.
+    pub self_ty: String,
+    /// The trait name without generic arguments, or `None` for an inherent impl.
+    pub trait_ty: Option<String>,
+    /// The generic arguments of the trait.
+    pub trait_args: Vec<String>,
+    /// The method name.
+    pub method: String,
+    /// The module path from the namespace descriptors, e.g. `geometry`.
+    pub module: String,
+}
+
+impl From<ParsedSymbol> for ImplDescriptor {
+    fn from(parsed: ParsedSymbol) -> Self {
+        ImplDescriptor {
+            self_ty: parsed.self_ty,
+            trait_ty: parsed.trait_ty,
+            trait_args: parsed.trait_args,
+            method: parsed.method,
+            source_line: 0,
+            module: parsed.module,
+        }
+    }
+}
+
+/// The reasons a rust-analyzer symbol can fail to parse.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The symbol does not start with `impl#`.
+    MissingImplPrefix,
+    /// A `[` or a backtick has no matching closing delimiter.
+    UnbalancedBrackets,
+    /// The symbol does not end with `().`.
+    MissingMethodSuffix,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingImplPrefix => write!(f, "symbol does not start with `impl#`"),
+            ParseError::UnbalancedBrackets => write!(f, "symbol has unbalanced brackets"),
+            ParseError::MissingMethodSuffix => write!(f, "symbol does not end with `().`"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The kinds of symbol [`classify`] tells apart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SymbolKind {
+    /// A method of a trait impl, e.g. `impl#[Scalar][Neg]neg().`.
+    TraitMethod,
+    /// A method of an inherent impl, e.g. `impl#[Scalar]checked_neg().`.
+    InherentMethod,
+    /// An associated type, e.g. `impl#[Scalar][Neg]Output#`.
+    AssociatedType,
+    /// An associated const, e.g. `impl#[Scalar][Named]NAME.`.
+    AssociatedConst,
+    /// Anything that is not an impl member, such as the type symbol `Scalar#`.
+    Other,
+}
+
+/// Classifies an impl member symbol in either format by its shape.
+///
+/// The scheme/package prefix may be present or not. The rules are:
+/// - a symbol ending in `().` is a method. In rust-analyzer symbols it is a
+///   trait method if the `impl#[Self]` is followed by a `[Trait]`. In
+///   verus-analyzer symbols it is a trait method if it has two `#`s, as in
+///   `Scalar#Neg#neg().`;
+/// - any other symbol ending in `.` is an associated const;
+/// - `impl#[Self][Trait]Name#` (rust-analyzer) and `Self#Trait#[Name]`
+///   (verus-analyzer) are associated types.
+///
+/// Anything else, such as the type symbol `Scalar#`, is [`SymbolKind::Other`].
+///
+/// verus-analyzer drops some Self types, so a trait method like `Neg#neg().`
+/// has a single `#` and is indistinguishable from an inherent method such as
+/// `Scalar#checked_neg().`; both are classified as
+/// [`SymbolKind::InherentMethod`].
+pub fn classify(symbol: &str) -> SymbolKind {
+    let rust_analyzer_impl = symbol
+        .find("impl#[")
+        .map(|idx| &symbol[idx + "impl#".len()..]);
+    if symbol.ends_with("().") {
+        let is_trait_method = match rust_analyzer_impl {
+            Some(rest) => parse_bracketed(rest).is_ok_and(|(_, rest)| rest.starts_with('[')),
+            None => symbol.matches('#').count() >= 2,
+        };
+        if is_trait_method {
+            SymbolKind::TraitMethod
+        } else {
+            SymbolKind::InherentMethod
+        }
+    } else if symbol.ends_with('.') {
+        SymbolKind::AssociatedConst
+    } else if (rust_analyzer_impl.is_some() && symbol.ends_with('#'))
+        || (symbol.ends_with(']') && symbol.contains("#["))
+    {
+        SymbolKind::AssociatedType
+    } else {
+        SymbolKind::Other
+    }
+}
+
+/// Canonicalizes the backtick quoting of a symbol.
+///
+/// Backticks around names that rust-analyzer would not quote are removed, e.g.
+/// ``impl#[`Scalar`][Neg]neg().`` becomes `impl#[Scalar][Neg]neg().`, while
+/// ``impl#[`&Scalar`][Neg]neg().`` is unchanged. An unmatched backtick and
+/// everything after it are kept as they are.
+pub fn normalize_symbol(s: &str) -> String {
+    let mut normalized = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(open) = rest.find('`') {
+        normalized.push_str(&rest[..open]);
+        let quoted = &rest[open + 1..];
+        let Some(close) = quoted.find('`') else {
+            normalized.push_str(&rest[open..]);
+            return normalized;
+        };
+        let name = &quoted[..close];
+        if needs_quoting(name) {
+            normalized.push('`');
+            normalized.push_str(name);
+            normalized.push('`');
+        } else {
+            normalized.push_str(name);
+        }
+        rest = &quoted[close + 1..];
+    }
+    normalized.push_str(rest);
+    normalized
+}
+
+/// Decomposes a rust-analyzer impl method symbol such as
+/// ``impl#[`&Point`][`Mul<&Scalar>`]mul().`` into its components.
+///
+/// Leading namespace descriptors, as in `geometry/impl#[Vec2][Neg]neg().`,
+/// become the `module` path.
+pub fn parse_rust_analyzer_symbol(s: &str) -> Result<ParsedSymbol, ParseError> {
+    let mut segments = Vec::new();
+    let mut rest = s;
+    while let Some((segment, tail)) = rest.split_once('/') {
+        if segment.is_empty() || !segment.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            break;
+        }
+        segments.push(segment);
+        rest = tail;
+    }
+    let rest = rest
+        .strip_prefix("impl#")
+        .ok_or(ParseError::MissingImplPrefix)?;
+    let (self_ty, rest) = parse_bracketed(rest)?;
+    let (trait_ref, rest) = if rest.starts_with('[') {
+        let (trait_ref, rest) = parse_bracketed(rest)?;
+        (Some(trait_ref), rest)
+    } else {
+        (None, rest)
+    };
+    let method = rest
+        .strip_suffix("().")
+        .ok_or(ParseError::MissingMethodSuffix)?;
+    let (trait_ty, trait_args) = match trait_ref {
+        Some(trait_ref) => {
+            let (name, args) = split_generics(trait_ref);
+            (Some(name.to_string()), args)
+        }
+        None => (None, Vec::new()),
+    };
+    Ok(ParsedSymbol {
+        self_ty: self_ty.to_string(),
+        trait_ty,
+        trait_args,
+        method: method.to_string(),
+        module: segments.join("::"),
+    })
+}
+
+/// Parses a leading `[name]` or ``[`name`]`` group, returning the unquoted
+/// name and the remainder of the input.
+fn parse_bracketed(s: &str) -> Result<(&str, &str), ParseError> {
+    let inner = s.strip_prefix('[').ok_or(ParseError::UnbalancedBrackets)?;
+    let (name, rest) = match inner.strip_prefix('`') {
+        Some(quoted) => {
+            let end = quoted.find('`').ok_or(ParseError::UnbalancedBrackets)?;
+            (&quoted[..end], &quoted[end + 1..])
+        }
+        None => {
+            let end = inner.find(']').ok_or(ParseError::UnbalancedBrackets)?;
+            if inner[..end].contains('[') {
+                return Err(ParseError::UnbalancedBrackets);
+            }
+            (&inner[..end], &inner[end..])
+        }
+    };
+    let rest = rest
+        .strip_prefix(']')
+        .ok_or(ParseError::UnbalancedBrackets)?;
+    Ok((name, rest))
+}
+
+/// Splits `Mul<&Scalar>` into `Mul` and `["&Scalar"]`, respecting nesting.
+fn split_generics(ty: &str) -> (&str, Vec<String>) {
+    let Some(start) = ty.find('<') else {
+        return (ty, Vec::new());
+    };
+    let inner = ty[start + 1..]
+        .strip_suffix('>')
+        .unwrap_or(&ty[start + 1..]);
+    let mut args = Vec::new();
+    let mut depth = 0;
+    let mut arg_start = 0;
+    for (idx, c) in inner.char_indices() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(inner[arg_start..idx].trim().to_string());
+                arg_start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    args.push(inner[arg_start..].trim().to_string());
+    (&ty[..start], args)
+}
+
+/// Quotes a type for use as a rust-analyzer descriptor name.
+///
+/// The type is wrapped in backticks iff it contains `&`, `<`, `>` or
+/// whitespace, so `Scalar` is kept as is but `&Scalar` becomes `` `&Scalar` ``.
+/// verus-analyzer never quotes: the names it emits are always stripped down to
+/// plain identifiers.
+pub fn escape_type(ty: &str) -> String {
+    let mut escaped = String::new();
+    write_escaped(&mut escaped, ty).expect("writing to a String cannot fail");
+    escaped
+}
+
+/// Writes `name` to `out`, quoted as by [`escape_type`].
+fn write_escaped<W: fmt::Write>(out: &mut W, name: &str) -> fmt::Result {
+    if needs_quoting(name) {
+        write!(out, "`{}`", name)
+    } else {
+        out.write_str(name)
+    }
+}
+
+/// Whether rust-analyzer quotes `name` with backticks.
+fn needs_quoting(name: &str) -> bool {
+    name.chars()
+        .any(|c| c == '&' || c == '<' || c == '>' || c.is_whitespace())
+}
+
+/// Removes generic arguments from a type, e.g. `Container<TypeA>` -> `Container`.
+fn strip_generics(ty: &str) -> &str {
+    match ty.find('<') {
+        Some(idx) => &ty[..idx],
+        None => ty,
+    }
+}
+
+/// Returns the ADT name of a type, or `None` if the type is not an ADT.
+///
+/// References, pointers, tuples, arrays, slices, trait objects, primitives and
+/// type parameters are not ADTs. Type parameters are recognized by the usual
+/// single-uppercase-letter naming, e.g. `T`.
+fn adt_name(ty: &str) -> Option<&str> {
+    let ty = ty.trim();
+    if ty.starts_with(['&', '*', '(', '[']) || ty.starts_with("dyn ") || ty.starts_with("fn(") {
+        return None;
+    }
+    let path = strip_generics(ty);
+    let name = path.rsplit("::").next().unwrap_or(path);
+    if name.is_empty() || PRIMITIVES.contains(&name) || is_type_param(name) {
+        return None;
+    }
+    Some(name)
+}
+
+/// Whether a name looks like a type parameter, e.g. `T` or `U2`.
+fn is_type_param(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_uppercase()) && chars.all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testgen;
+
+    #[test]
+    fn test_case1_owned_self() {
+        assert_eq!(
+            rust_analyzer_trait_impl_symbol("Scalar", "Neg", "neg"),
+            "impl#[Scalar][Neg]neg()."
+        );
+        assert_eq!(
+            verus_analyzer_trait_impl_symbol("Scalar", "Neg", "neg"),
+            "Scalar#Neg#neg()."
+        );
+    }
+
+    #[test]
+    fn test_case2_reference_self() {
+        assert_eq!(
+            rust_analyzer_trait_impl_symbol("&Scalar", "Neg", "neg"),
+            "impl#[`&Scalar`][Neg]neg()."
+        );
+        assert_eq!(
+            verus_analyzer_trait_impl_symbol("&Scalar", "Neg", "neg"),
+            "Neg#neg()."
+        );
+    }
+
+    #[test]
+    fn test_case3_mul() {
+        assert_eq!(
+            rust_analyzer_trait_impl_symbol("&Point", "Mul<&Scalar>", "mul"),
+            "impl#[`&Point`][`Mul<&Scalar>`]mul()."
+        );
+        assert_eq!(
+            rust_analyzer_trait_impl_symbol("&Scalar", "Mul<&Point>", "mul"),
+            "impl#[`&Scalar`][`Mul<&Point>`]mul()."
+        );
+        assert_eq!(
+            verus_analyzer_trait_impl_symbol("&Point", "Mul<&Scalar>", "mul"),
+            "Mul#mul()."
+        );
+        assert_eq!(
+            verus_analyzer_trait_impl_symbol("&Scalar", "Mul<&Point>", "mul"),
+            "Mul#mul()."
+        );
+    }
+
+    #[test]
+    fn test_case4_from() {
+        assert_eq!(
+            rust_analyzer_trait_impl_symbol("Container<TypeA>", "From<&Scalar>", "from"),
+            "impl#[`Container<TypeA>`][`From<&Scalar>`]from()."
+        );
+        assert_eq!(
+            rust_analyzer_trait_impl_symbol("Container<TypeB>", "From<&Scalar>", "from"),
+            "impl#[`Container<TypeB>`][`From<&Scalar>`]from()."
+        );
+        assert_eq!(
+            verus_analyzer_trait_impl_symbol("Container<TypeA>", "From<&Scalar>", "from"),
+            "Container#From#from()."
+        );
+        assert_eq!(
+            verus_analyzer_trait_impl_symbol("Container<TypeB>", "From<&Scalar>", "from"),
+            "Container#From#from()."
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "verus")]
+    fn test_default_format_is_verus_analyzer() {
+        assert_eq!(DEFAULT_FORMAT, SymbolFormat::VerusAnalyzer);
+        assert_eq!(SymbolFormat::default(), SymbolFormat::VerusAnalyzer);
+    }
+
+    #[test]
+    #[cfg(not(feature = "verus"))]
+    fn test_default_format_is_rust_analyzer() {
+        assert_eq!(DEFAULT_FORMAT, SymbolFormat::RustAnalyzer);
+        assert_eq!(SymbolFormat::default(), SymbolFormat::RustAnalyzer);
+    }
+
+    #[test]
+    fn test_format_impl_dispatch() {
+        let symbols: Vec<String> = SymbolFormat::ALL
+            .iter()
+            .map(|format| format.format_impl("&Scalar", "Neg", "neg"))
+            .collect();
+        assert_eq!(symbols, ["impl#[`&Scalar`][Neg]neg().", "Neg#neg()."]);
+    }
+
+    #[test]
+    fn test_inherent_impl_symbols() {
+        let d = ImplDescriptor::inherent("Scalar", "convert");
+        assert_eq!(d.trait_ty, None);
+        assert_eq!(
+            d.to_symbol(SymbolFormat::RustAnalyzer),
+            "impl#[Scalar]convert()."
+        );
+        assert_eq!(
+            d.to_symbol(SymbolFormat::VerusAnalyzer),
+            "Scalar#convert()."
+        );
+    }
+
+    #[test]
+    fn test_reference_self_is_preserved() {
+        let d = ImplDescriptor::new("&Point", Some("Mul"), &["&Scalar"], "mul");
+        assert_eq!(d.self_ty, "&Point");
+        assert_eq!(d.trait_args, ["&Scalar"]);
+        assert_eq!(
+            d.to_symbol(SymbolFormat::RustAnalyzer),
+            "impl#[`&Point`][`Mul<&Scalar>`]mul()."
+        );
+        assert_eq!(d.to_symbol(SymbolFormat::VerusAnalyzer), "Mul#mul().");
+    }
+
+    #[test]
+    fn test_find_duplicate_symbols() {
+        let impls = crate::all_crate_impls();
+        let documented = &impls[..6];
+        assert_eq!(
+            find_duplicate_symbols(documented, SymbolFormat::VerusAnalyzer),
+            [
+                ("Mul#mul().".to_string(), vec![2, 3]),
+                ("Container#From#from().".to_string(), vec![4, 5]),
+            ]
+        );
+        assert!(find_duplicate_symbols(documented, SymbolFormat::RustAnalyzer).is_empty());
+
+        let symbols: Vec<String> = find_duplicate_symbols(&impls, SymbolFormat::VerusAnalyzer)
+            .into_iter()
+            .map(|(symbol, _)| symbol)
+            .collect();
+        assert_eq!(
+            symbols,
+            [
+                "Neg#neg().",
+                "Mul#mul().",
+                "Container#From#from().",
+                "Add#add().",
+                "Scalar#From#from().",
+                "Pair#From#from().",
+                "ElementwiseNeg#neg().",
+                "Scalar#PartialEq#eq().",
+                "Scalar#Mul#mul().",
+            ]
+        );
+        assert!(find_duplicate_symbols(&impls, SymbolFormat::RustAnalyzer).is_empty());
+    }
+
+    #[test]
+    fn test_source_lines() {
+        let source = include_str!("lib.rs");
+        let line_of = |header: &str| {
+            source
+                .lines()
+                .position(|line| line.starts_with(header))
+                .map(|idx| idx as u32 + 1)
+                .unwrap()
+        };
+        let impls = crate::all_crate_impls();
+        assert!(impls.iter().all(|d| d.source_line > 0));
+        assert_eq!(impls[1].source_line, line_of("impl Neg for &Scalar {"));
+        assert_eq!(
+            impls[2].source_line,
+            line_of("impl Mul<&Scalar> for &Point {")
+        );
+        assert_eq!(
+            impls[3].source_line,
+            line_of("impl Mul<&Point> for &Scalar {")
+        );
+        let raw_neg = impls.iter().find(|d| d.method == "raw_neg").unwrap();
+        assert_eq!(
+            raw_neg.source_line,
+            line_of("impl RawNeg for *const Scalar {")
+        );
+    }
+
+    #[test]
+    fn test_has_receiver() {
+        let source = include_str!("lib.rs");
+        let impls = crate::all_crate_impls();
+        let receiverless: Vec<String> = impls
+            .iter()
+            .filter(|d| !has_receiver(d, source))
+            .map(|d| d.to_string())
+            .collect();
+        for path in [
+            "<Scalar as Default>::default",
+            "<Point as Default>::default",
+            "<Scalar as From<i32>>::from",
+            "<Scalar as FromStr>::from_str",
+            "<Sign as TryFrom<&Scalar>>::try_from",
+            "<Scalar as Sum<Self>>::sum",
+            "ScalarRange::new",
+        ] {
+            assert!(receiverless.iter().any(|p| p == path), "{}", path);
+        }
+        for (idx, method) in [(0, "neg"), (2, "mul")] {
+            assert_eq!(impls[idx].method, method);
+            assert!(has_receiver(&impls[idx], source));
+        }
+        let apply = impls.iter().find(|d| d.method == "apply").unwrap();
+        assert!(has_receiver(apply, source));
+        assert!(has_receiver(
+            &ImplDescriptor::new("Scalar", Some("Default"), &[], "default"),
+            source
+        ));
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let impls = crate::all_crate_impls();
+        let dot = to_dot(&impls);
+        assert!(dot.starts_with("graph collisions {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.contains("    n2 [label=\"<&Point as Mul<&Scalar>>::mul\"];\n"));
+        // The two Case 3 impls.
+        assert!(dot.contains("    n2 -- n3 [label=\"Mul#mul().\"];\n"));
+        assert!(!dot.contains("n0 --"));
+        assert_eq!(dot_string("a\"b\\c"), "\"a\\\"b\\\\c\"");
+    }
+
+    #[test]
+    fn test_collision_report() {
+        let impls = crate::all_crate_impls();
+        let report = collision_report();
+        let mul_line = report
+            .lines()
+            .find(|line| line.starts_with("Duplicate 'Mul#mul().'"))
+            .unwrap();
+        // The two Case 3 impls come first; later cases add more members.
+        assert!(mul_line.starts_with(&format!(
+            "Duplicate 'Mul#mul().' at lines {}, {}, ",
+            impls[2].source_line, impls[3].source_line
+        )));
+        assert!(report.contains(&format!(
+            "Duplicate 'Add#add().' at lines {} and {}\n",
+            impls[7].source_line, impls[8].source_line
+        )));
+    }
+
+    #[test]
+    fn test_symbol_builder_matches_to_symbol() {
+        let impls = crate::all_crate_impls();
+        let builders = [
+            SymbolBuilder::new()
+                .self_type("Scalar")
+                .trait_name("Neg")
+                .method("neg"),
+            SymbolBuilder::new()
+                .self_type("&Scalar")
+                .trait_name("Neg")
+                .method("neg"),
+            SymbolBuilder::new()
+                .self_type("&Point")
+                .trait_name("Mul")
+                .trait_arg("&Scalar")
+                .method("mul"),
+            SymbolBuilder::new()
+                .self_type("&Scalar")
+                .trait_name("Mul")
+                .trait_arg("&Point")
+                .method("mul"),
+            SymbolBuilder::new()
+                .self_type("Container<TypeA>")
+                .trait_name("From")
+                .trait_arg("&Scalar")
+                .method("from"),
+        ];
+        for (builder, descriptor) in builders.iter().zip(&impls) {
+            for format in SymbolFormat::ALL {
+                assert_eq!(builder.build(format), descriptor.to_symbol(format));
+            }
+        }
+        assert_eq!(
+            SymbolBuilder::new()
+                .self_type("Scalar")
+                .method("checked_neg")
+                .build(SymbolFormat::VerusAnalyzer),
+            "Scalar#checked_neg()."
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "without a method")]
+    fn test_symbol_builder_requires_method() {
+        SymbolBuilder::new()
+            .self_type("Scalar")
+            .build(SymbolFormat::RustAnalyzer);
+    }
+
+    #[test]
+    fn test_relationships() {
+        let impls = crate::all_crate_impls();
+        let neg = Relationship {
+            symbol: format!("{} ops/arith/Neg#neg().", CORE_PACKAGE),
+            kind: RelationshipKind::Implementation,
+        };
+        assert_eq!(
+            impls[0].to_symbol(SymbolFormat::RustAnalyzer),
+            "impl#[Scalar][Neg]neg()."
+        );
+        assert_eq!(relationships(&impls[0]), relationships(&impls[1]));
+        assert_eq!(relationships(&impls[0]), [neg]);
+        assert_eq!(
+            relationships(&impls[2])[0].symbol,
+            format!("{} ops/arith/Mul#mul().", CORE_PACKAGE)
+        );
+        assert_eq!(
+            relationships(&ImplDescriptor::new("Point", Some("Shape"), &[], "area"))[0].symbol,
+            "rust-analyzer cargo minimal-scip-issue 0.1.0 Shape#area()."
+        );
+        assert_eq!(
+            relationships(&ImplDescriptor::inherent("Scalar", "checked_neg")),
+            []
+        );
+    }
+
+    #[test]
+    fn test_relationship_targets_are_not_impl_symbols() {
+        let impls = crate::all_crate_impls();
+        let symbols: HashSet<String> = SymbolFormat::ALL
+            .iter()
+            .flat_map(|&format| impls.iter().map(move |d| d.to_symbol(format)))
+            .collect();
+        for descriptor in &impls {
+            for relationship in relationships(descriptor) {
+                assert!(
+                    !symbols.contains(&relationship.symbol),
+                    "{} relates to an impl symbol",
+                    descriptor
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_non_core_traits_are_crate_traits() {
+        let source = include_str!("lib.rs");
+        for descriptor in crate::all_crate_impls() {
+            let Some(trait_ty) = &descriptor.trait_ty else {
+                continue;
+            };
+            let trait_name = strip_generics(trait_ty);
+            if !CORE_TRAITS.iter().any(|(name, _)| *name == trait_name) {
+                assert!(
+                    source.contains(&format!("pub trait {}", trait_name)),
+                    "{} is neither a core trait nor defined in the crate",
+                    trait_name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_symbol_for_method() {
+        let path = "<&Scalar as Neg>::neg";
+        assert_eq!(
+            symbol_for_method(path, SymbolFormat::RustAnalyzer).as_deref(),
+            Some("impl#[`&Scalar`][Neg]neg().")
+        );
+        assert_eq!(
+            symbol_for_method(path, SymbolFormat::VerusAnalyzer).as_deref(),
+            Some("Neg#neg().")
+        );
+        assert_eq!(
+            symbol_for_method("<&Point as Mul<&Scalar>>::mul", SymbolFormat::RustAnalyzer)
+                .as_deref(),
+            Some("impl#[`&Point`][`Mul<&Scalar>`]mul().")
+        );
+        assert_eq!(
+            symbol_for_method("Scalar::checked_neg", SymbolFormat::VerusAnalyzer).as_deref(),
+            Some("Scalar#checked_neg().")
+        );
+        assert_eq!(
+            symbol_for_method("<Scalar as Bogus>::neg", SymbolFormat::RustAnalyzer),
+            None
+        );
+    }
+
+    #[test]
+    fn test_escape_type() {
+        assert_eq!(escape_type("Scalar"), "Scalar");
+        assert_eq!(escape_type("i32"), "i32");
+        assert_eq!(escape_type("&Scalar"), "`&Scalar`");
+        assert_eq!(escape_type("Mul<&Scalar>"), "`Mul<&Scalar>`");
+        assert_eq!(escape_type("Container<TypeA>"), "`Container<TypeA>`");
+        assert_eq!(escape_type("dyn Shape"), "`dyn Shape`");
+    }
+
+    #[test]
+    fn test_trait_ref_render() {
+        let mul = TraitRef {
+            name: "Mul".to_string(),
+            args: vec!["&Scalar".to_string()],
+        };
+        assert_eq!(mul.render(), "Mul<&Scalar>");
+        let neg = TraitRef {
+            name: "Neg".to_string(),
+            args: Vec::new(),
+        };
+        assert_eq!(neg.render(), "Neg");
+        let index = ImplDescriptor::new("LookupTable<T>", Some("Index"), &["usize"], "index");
+        assert_eq!(index.trait_ref().unwrap().render(), "Index<usize>");
+        assert_eq!(
+            ImplDescriptor::inherent("Scalar", "convert").trait_ref(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_write_symbol_into_shared_buffer() {
+        let impls = &crate::all_crate_impls()[..3];
+        let mut out = String::new();
+        for descriptor in impls {
+            write_symbol(descriptor, SymbolFormat::RustAnalyzer, &mut out).unwrap();
+        }
+        assert_eq!(
+            out,
+            "impl#[Scalar][Neg]neg().impl#[`&Scalar`][Neg]neg().impl#[`&Point`][`Mul<&Scalar>`]mul()."
+        );
+
+        out.clear();
+        for descriptor in impls {
+            write_symbol(descriptor, SymbolFormat::VerusAnalyzer, &mut out).unwrap();
+        }
+        assert_eq!(out, "Scalar#Neg#neg().Neg#neg().Mul#mul().");
+    }
+
+    #[test]
+    fn test_catalog_json_round_trip() {
+        for format in SymbolFormat::ALL {
+            let json = catalog_json(format);
+            let (parsed_format, impls) = parse_catalog_json(&json).unwrap();
+            assert_eq!(parsed_format, format);
+            assert_eq!(impls, crate::all_crate_impls());
+        }
+    }
+
+    #[test]
+    fn test_catalog_json_lines() {
+        let json = catalog_json(SymbolFormat::VerusAnalyzer);
+        assert!(json.starts_with("{\n  \"format\": \"verus-analyzer\",\n"));
+        let line = json.lines().nth(4).unwrap();
+        assert!(line.starts_with(
+            "    {\"self_ty\": \"&Scalar\", \"trait_ty\": \"Neg\", \"trait_args\": [], \"method\": \"neg\""
+        ));
+        assert!(line.ends_with(", \"symbol\": \"Neg#neg().\"},"));
+    }
+
+    #[test]
+    fn test_symbol_report_json_parses() {
+        let impls = crate::all_crate_impls();
+        let report = json::parse(&symbol_report_json(&impls)).unwrap();
+        let entries = report.get("impls").and_then(json::Value::as_array).unwrap();
+        assert_eq!(entries.len(), impls.len());
+        assert_eq!(
+            entries[1].get("verus_analyzer"),
+            Some(&json::Value::String("Neg#neg().".to_string()))
+        );
+        assert_eq!(entries[0].get("duplicate"), Some(&json::Value::Bool(false)));
+        assert_eq!(entries[1].get("duplicate"), Some(&json::Value::Bool(true)));
+        let groups = report
+            .get("collisions")
+            .and_then(json::Value::as_array)
+            .unwrap();
+        let collisions = find_duplicate_symbols(&impls, SymbolFormat::VerusAnalyzer);
+        assert_eq!(groups.len(), collisions.len());
+
+        let field = |value: &json::Value, key: &str| {
+            value
+                .get(key)
+                .and_then(json::Value::as_str)
+                .unwrap()
+                .to_string()
+        };
+        for (idx, descriptor) in impls.iter().enumerate() {
+            let path = descriptor.to_string();
+            let entry = entries
+                .iter()
+                .find(|entry| field(entry, "path") == path)
+                .unwrap_or_else(|| panic!("no entry for {}", path));
+            assert_eq!(
+                field(entry, "rust_analyzer"),
+                descriptor.to_symbol(SymbolFormat::RustAnalyzer)
+            );
+            assert_eq!(
+                field(entry, "verus_analyzer"),
+                descriptor.to_symbol(SymbolFormat::VerusAnalyzer)
+            );
+            let duplicate = collisions.iter().any(|(_, members)| members.contains(&idx));
+            assert_eq!(entry.get("duplicate"), Some(&json::Value::Bool(duplicate)));
+        }
+
+        let mul = groups
+            .iter()
+            .find(|group| field(group, "symbol") == "Mul#mul().")
+            .unwrap();
+        let paths: Vec<String> = mul
+            .get("impls")
+            .and_then(json::Value::as_array)
+            .unwrap()
+            .iter()
+            .map(|idx| field(&entries[idx.as_u64().unwrap() as usize], "path"))
+            .collect();
+        assert!(paths.contains(&"<&Point as Mul<&Scalar>>::mul".to_string()));
+        assert!(paths.contains(&"<&Scalar as Mul<&Point>>::mul".to_string()));
+    }
+
+    #[test]
+    fn test_parse_catalog_json_errors() {
+        assert_eq!(parse_catalog_json(""), Err(JsonError::UnexpectedEof));
+        assert_eq!(
+            parse_catalog_json("{} x"),
+            Err(JsonError::UnexpectedChar(3))
+        );
+        assert_eq!(
+            parse_catalog_json("{\"format\": \"rust-analyzer\"}"),
+            Err(JsonError::InvalidField("impls"))
+        );
+        assert_eq!(
+            parse_catalog_json("{\"format\": \"lsif\", \"impls\": []}"),
+            Err(JsonError::InvalidField("format"))
+        );
+        assert_eq!(
+            parse_catalog_json(
+                "{\"format\": \"rust-analyzer\", \"impls\": [{\"trait_args\": []}]}"
+            ),
+            Err(JsonError::InvalidField("trait_ty"))
+        );
+    }
+
+    #[test]
+    fn test_json_string_escapes() {
+        let mut out = String::new();
+        json::write_string(&mut out, "a\"b\\c\n\u{1}");
+        assert_eq!(out, r#""a\"b\\c\n\u0001""#);
+        assert_eq!(
+            json::parse(&out),
+            Ok(json::Value::String("a\"b\\c\n\u{1}".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_json_unicode_escapes() {
+        assert_eq!(
+            json::parse(r#""\u00e9\uD83D\uDE00""#),
+            Ok(json::Value::String("\u{e9}\u{1F600}".to_string()))
+        );
+        for bad in [
+            r#""\u+0e9""#,
+            r#""\u00""#,
+            r#""\u00g9""#,
+            r#""\uD83D""#,
+            r#""\uD83DA""#,
+            r#""\uDE00""#,
+        ] {
+            assert!(
+                matches!(json::parse(bad), Err(JsonError::UnexpectedChar(1))),
+                "{}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_json_rejects_control_characters() {
+        assert_eq!(json::parse("\"a\nb\""), Err(JsonError::UnexpectedChar(2)));
+        assert_eq!(json::parse("\"\t\""), Err(JsonError::UnexpectedChar(1)));
+        assert_eq!(
+            json::parse(r#""a\nb""#),
+            Ok(json::Value::String("a\nb".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_json_unsupported_numbers() {
+        assert_eq!(json::parse("[1, -2]"), Err(JsonError::UnsupportedNumber(4)));
+        assert_eq!(json::parse("1.5"), Err(JsonError::UnsupportedNumber(0)));
+        assert_eq!(json::parse("[10e3]"), Err(JsonError::UnsupportedNumber(1)));
+        assert_eq!(
+            json::parse("18446744073709551616"),
+            Err(JsonError::UnsupportedNumber(0))
+        );
+        assert_eq!(json::parse("42"), Ok(json::Value::Number(42)));
+    }
+
+    #[test]
+    fn test_json_depth_limit() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(json::parse(&nested(json::MAX_DEPTH)).is_ok());
+        assert_eq!(
+            json::parse(&nested(json::MAX_DEPTH + 1)),
+            Err(JsonError::TooDeep(json::MAX_DEPTH))
+        );
+        assert_eq!(
+            json::parse(&"{\"a\": ".repeat(json::MAX_DEPTH + 1)),
+            Err(JsonError::TooDeep(6 * json::MAX_DEPTH))
+        );
+    }
+
+    #[test]
+    fn test_symbol_length_histogram() {
+        let total_length = |histogram: &BTreeMap<usize, usize>| -> usize {
+            histogram.iter().map(|(len, count)| len * count).sum()
+        };
+        let rust_analyzer = symbol_length_histogram(SymbolFormat::RustAnalyzer);
+        let verus_analyzer = symbol_length_histogram(SymbolFormat::VerusAnalyzer);
+        let impls = crate::all_crate_impls().len();
+        assert_eq!(rust_analyzer.values().sum::<usize>(), impls);
+        assert_eq!(verus_analyzer.values().sum::<usize>(), impls);
+        assert!(total_length(&verus_analyzer) < total_length(&rust_analyzer));
+        assert!(verus_analyzer.keys().max() < rust_analyzer.keys().max());
+        assert!(verus_analyzer.keys().min() < rust_analyzer.keys().min());
+    }
+
+    #[test]
+    fn test_symbol_stats() {
+        // The Case 3 and Case 4 impls.
+        let impls = &crate::all_crate_impls()[2..6];
+        assert_eq!(
+            symbol_stats(impls),
+            SymbolStats {
+                total_impls: 4,
+                unique_rust_analyzer: 4,
+                unique_verus_analyzer: 2,
+                verus_collisions: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_normalize_symbol() {
+        assert_eq!(
+            normalize_symbol("impl#[`Scalar`][Neg]neg()."),
+            "impl#[Scalar][Neg]neg()."
+        );
+        assert_eq!(
+            normalize_symbol("impl#[`&Scalar`][`Neg`]neg()."),
+            "impl#[`&Scalar`][Neg]neg()."
+        );
+        assert_eq!(
+            normalize_symbol("impl#[Point][`From<(i32, i32)>`]from()."),
+            "impl#[Point][`From<(i32, i32)>`]from()."
+        );
+        assert_eq!(
+            normalize_symbol("impl#[`Scalar]neg()."),
+            "impl#[`Scalar]neg()."
+        );
+        for d in crate::all_crate_impls() {
+            let symbol = d.to_symbol(SymbolFormat::RustAnalyzer);
+            assert_eq!(normalize_symbol(&symbol), symbol);
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        let cases = [
+            ("impl#[Scalar][Neg]neg().", SymbolKind::TraitMethod),
+            ("impl#[`&Scalar`][Neg]neg().", SymbolKind::TraitMethod),
+            ("impl#[Scalar]checked_neg().", SymbolKind::InherentMethod),
+            ("impl#[Scalar][Named]NAME.", SymbolKind::AssociatedConst),
+            ("impl#[Scalar]ZERO.", SymbolKind::AssociatedConst),
+            ("impl#[Scalar][Neg]Output#", SymbolKind::AssociatedType),
+            ("Scalar#Neg#neg().", SymbolKind::TraitMethod),
+            ("Scalar#checked_neg().", SymbolKind::InherentMethod),
+            ("Neg#neg().", SymbolKind::InherentMethod),
+            ("Scalar#Named#NAME.", SymbolKind::AssociatedConst),
+            ("Scalar#Neg#[Output]", SymbolKind::AssociatedType),
+            ("Scalar#", SymbolKind::Other),
+            (
+                "rust-analyzer cargo minimal-scip-issue 0.1.0 impl#[Scalar][Neg]neg().",
+                SymbolKind::TraitMethod,
+            ),
+        ];
+        for (symbol, kind) in cases {
+            assert_eq!(classify(symbol), kind, "{}", symbol);
+        }
+        for d in crate::all_crate_impls() {
+            let expected = match d.trait_ty {
+                Some(_) => SymbolKind::TraitMethod,
+                None => SymbolKind::InherentMethod,
+            };
+            assert_eq!(classify(&d.to_symbol(SymbolFormat::RustAnalyzer)), expected);
+        }
+    }
+
+    #[test]
+    fn test_random_impls_never_collide_under_rust_analyzer() {
+        for seed in 0..20 {
+            let mut rng = testgen::Rng::new(seed);
+            let owned = testgen::descriptors(&mut rng, 50);
+            let impls: Vec<ImplDescriptor> = owned
+                .iter()
+                .cloned()
+                .chain(owned.iter().map(testgen::by_reference))
+                .collect();
+            assert!(
+                find_duplicate_symbols(&impls, SymbolFormat::RustAnalyzer).is_empty(),
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_random_reference_impls_collide_under_verus_analyzer() {
+        let mut rng = testgen::Rng::new(64);
+        let impls = testgen::descriptors(&mut rng, 100);
+        for pair in impls.chunks(2) {
+            let a = testgen::by_reference(&pair[0]);
+            // The same impl for a reference to a different Self type.
+            let b = ImplDescriptor {
+                self_ty: format!("&{}", pair[1].self_ty),
+                ..a.clone()
+            };
+            assert_eq!(
+                a.to_symbol(SymbolFormat::VerusAnalyzer),
+                b.to_symbol(SymbolFormat::VerusAnalyzer),
+                "{} vs {}",
+                a,
+                b
+            );
+            assert_ne!(
+                a.to_symbol(SymbolFormat::RustAnalyzer),
+                b.to_symbol(SymbolFormat::RustAnalyzer)
+            );
+            // Only the reference loses its Self type; the owned impl keeps it.
+            let owned = pair[0].to_symbol(SymbolFormat::VerusAnalyzer);
+            let adt = pair[0].self_ty.split('<').next().unwrap();
+            assert_eq!(
+                owned,
+                format!("{}#{}", adt, a.to_symbol(SymbolFormat::VerusAnalyzer))
+            );
+        }
+    }
+
+    #[test]
+    fn test_case4_collision_is_real() {
+        let a = ImplDescriptor::new("Container<TypeA>", Some("From"), &["&Scalar"], "from");
+        let b = ImplDescriptor::new("Container<TypeB>", Some("From"), &["&Scalar"], "from");
+        assert_eq!(
+            a.to_symbol(SymbolFormat::VerusAnalyzer),
+            b.to_symbol(SymbolFormat::VerusAnalyzer)
+        );
+        assert_ne!(
+            a.to_symbol(SymbolFormat::RustAnalyzer),
+            b.to_symbol(SymbolFormat::RustAnalyzer)
+        );
+    }
+
+    #[test]
+    fn test_diff_formats_self_type_dropped() {
+        let diffs = diff_formats(&crate::all_crate_impls());
+        let neg_owned = &diffs[0];
+        assert_eq!(neg_owned.descriptor.self_ty, "Scalar");
+        assert_eq!(neg_owned.verus_analyzer, "Scalar#Neg#neg().");
+        assert!(!neg_owned.self_type_dropped);
+
+        let neg_ref = &diffs[1];
+        assert_eq!(neg_ref.descriptor.self_ty, "&Scalar");
+        assert_eq!(neg_ref.rust_analyzer, "impl#[`&Scalar`][Neg]neg().");
+        assert_eq!(neg_ref.verus_analyzer, "Neg#neg().");
+        assert!(neg_ref.self_type_dropped);
+    }
+
+    #[test]
+    fn test_symbol_table() {
+        let impls = crate::all_crate_impls();
+        let table = symbol_table(SymbolFormat::VerusAnalyzer);
+        let mul = &table["Mul#mul()."];
+        assert!(mul.contains(&impls[2]) && mul.contains(&impls[3]));
+        assert!(table["Scalar#Neg#neg()."].contains(&impls[0]));
+
+        let table = symbol_table(SymbolFormat::RustAnalyzer);
+        assert!(table.values().all(|impls| impls.len() == 1));
+    }
+
+    #[test]
+    fn test_descriptor_display() {
+        let d = ImplDescriptor::new("&Point", Some("Mul"), &["&Scalar"], "mul");
+        assert_eq!(d.to_string(), "<&Point as Mul<&Scalar>>::mul");
+        let d = ImplDescriptor::new("&Scalar", Some("Neg"), &[], "neg");
+        assert_eq!(d.to_string(), "<&Scalar as Neg>::neg");
+        assert_eq!(
+            ImplDescriptor::inherent("Scalar", "convert").to_string(),
+            "Scalar::convert"
+        );
+    }
+
+    #[test]
+    fn test_assert_unique_symbols_passes_for_rust_analyzer() {
+        crate::assert_unique_symbols!(SymbolFormat::RustAnalyzer, crate::all_crate_impls());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "`Mul#mul().` is shared by:\n    <&Point as Mul<&Scalar>>::mul\n    <&Scalar as Mul<&Point>>::mul"
+    )]
+    fn test_assert_unique_symbols_fails_for_verus_analyzer() {
+        crate::assert_unique_symbols!(SymbolFormat::VerusAnalyzer, crate::all_crate_impls());
+    }
+
+    #[test]
+    fn test_disambiguate() {
+        let impls = crate::all_crate_impls();
+        let format = SymbolFormat::VerusAnalyzer;
+        let mut symbols: Vec<String> = impls.iter().map(|d| d.to_symbol(format)).collect();
+        for (symbol, members) in find_duplicate_symbols(&impls, format) {
+            for (position, &idx) in members.iter().enumerate() {
+                symbols[idx] = disambiguate(&symbol, position, members.len());
+            }
+        }
+        // Case 1's `Neg for Scalar` is unique, so it must come out unchanged.
+        assert_eq!(impls[0].to_string(), "<Scalar as Neg>::neg");
+        assert_eq!(symbols[0], "Scalar#Neg#neg().");
+        assert_eq!(symbols[2], "Mul#mul().#1");
+        assert_eq!(symbols[3], "Mul#mul().#2");
+        assert_eq!(disambiguate("Scalar#Neg#neg().", 0, 1), "Scalar#Neg#neg().");
+    }
+
+    #[test]
+    fn test_parse_rust_analyzer_symbol() {
+        assert_eq!(
+            parse_rust_analyzer_symbol("impl#[`&Scalar`][Neg]neg()."),
+            Ok(ParsedSymbol {
+                self_ty: "&Scalar".to_string(),
+                trait_ty: Some("Neg".to_string()),
+                trait_args: Vec::new(),
+                method: "neg".to_string(),
+                module: String::new(),
+            })
+        );
+        let parsed = parse_rust_analyzer_symbol("geometry/shapes/impl#[Vec2][Neg]neg().").unwrap();
+        assert_eq!(parsed.self_ty, "Vec2");
+        assert_eq!(parsed.module, "geometry::shapes");
+    }
+
+    #[test]
+    fn test_module_qualified_symbols() {
+        let d = ImplDescriptor::new("Vec2", Some("Neg"), &[], "neg").in_module("geometry");
+        assert_eq!(
+            d.to_symbol(SymbolFormat::RustAnalyzer),
+            "geometry/impl#[Vec2][Neg]neg()."
+        );
+        assert_eq!(
+            d.to_symbol(SymbolFormat::VerusAnalyzer),
+            "geometry/Vec2#Neg#neg()."
+        );
+        assert_eq!(d.to_string(), "<geometry::Vec2 as Neg>::neg");
+        let nested = ImplDescriptor::inherent("Vec2", "len").in_module("geometry::shapes");
+        assert_eq!(
+            nested.to_symbol(SymbolFormat::VerusAnalyzer),
+            "geometry/shapes/Vec2#len()."
+        );
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let impls = crate::all_crate_impls();
+        for d in impls
+            .iter()
+            .chain([&ImplDescriptor::inherent("Scalar", "convert")])
+        {
+            let symbol = d.to_symbol(SymbolFormat::RustAnalyzer);
+            let parsed = parse_rust_analyzer_symbol(&symbol).unwrap();
+            // Symbols carry no source location, so only the line can differ.
+            let round_tripped = ImplDescriptor {
+                source_line: d.source_line,
+                ..ImplDescriptor::from(parsed)
+            };
+            assert_eq!(&round_tripped, d, "{}", symbol);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_rust_analyzer_symbol("Scalar#Neg#neg()."),
+            Err(ParseError::MissingImplPrefix)
+        );
+        assert_eq!(
+            parse_rust_analyzer_symbol("impl#[Scalar[Neg]neg()."),
+            Err(ParseError::UnbalancedBrackets)
+        );
+        assert_eq!(
+            parse_rust_analyzer_symbol("impl#[`&Scalar][Neg]neg()."),
+            Err(ParseError::UnbalancedBrackets)
+        );
+        assert_eq!(
+            parse_rust_analyzer_symbol("impl#[Scalar][Neg]neg"),
+            Err(ParseError::MissingMethodSuffix)
+        );
+    }
+}
@@ -0,0 +1,311 @@
+//! A minimal JSON reader and writer.
+//!
+//! Only what the symbol catalog needs is supported: objects, arrays, strings,
+//! non-negative integers, booleans and `null`. Negative and fractional numbers
+//! are rejected with [`JsonError::UnsupportedNumber`].
+//!
+//! The crate has no dependencies, and the environments it is built in cannot
+//! fetch `serde` or `serde_json`, so the catalog is serialized by hand rather
+//! than through an optional `serde` feature. Values may nest at most
+//! [`MAX_DEPTH`] levels deep.
+
+use std::fmt;
+
+/// How deeply arrays and objects may nest. The catalog needs three levels.
+pub(crate) const MAX_DEPTH: usize = 32;
+
+/// The reasons a JSON document can fail to parse.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JsonError {
+    /// The input ended in the middle of a value.
+    UnexpectedEof,
+    /// The byte at this offset cannot start or continue a value.
+    UnexpectedChar(usize),
+    /// A required object field is missing or has the wrong type.
+    InvalidField(&'static str),
+    /// The array or object starting at this offset nests more than 32 levels deep.
+    TooDeep(usize),
+    /// The number at this offset is negative, fractional, has an exponent, or
+    /// does not fit in a `u64`.
+    UnsupportedNumber(usize),
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonError::UnexpectedEof => write!(f, "unexpected end of JSON input"),
+            JsonError::UnexpectedChar(offset) => {
+                write!(f, "unexpected character at offset {}", offset)
+            }
+            JsonError::InvalidField(name) => write!(f, "missing or invalid field `{}`", name),
+            JsonError::TooDeep(offset) => {
+                write!(f, "nesting deeper than {} at offset {}", MAX_DEPTH, offset)
+            }
+            JsonError::UnsupportedNumber(offset) => write!(
+                f,
+                "unsupported number at offset {} (only non-negative integers are allowed)",
+                offset
+            ),
+        }
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+/// A parsed JSON value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    Number(u64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Looks up a field of an object, or `None` for a missing field or a non-object.
+    pub(crate) fn get(&self, name: &str) -> Option<&Value> {
+        match self {
+            Value::Object(fields) => fields.iter().find(|(key, _)| key == name).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_u64(&self) -> Option<u64> {
+        match self {
+            Value::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+/// Writes `s` as a JSON string literal.
+pub(crate) fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Parses a complete JSON document.
+pub(crate) fn parse(input: &str) -> Result<Value, JsonError> {
+    let mut parser = Parser {
+        input,
+        pos: 0,
+        depth: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.pos < input.len() {
+        return Err(JsonError::UnexpectedChar(parser.pos));
+    }
+    Ok(value)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    /// The number of arrays and objects currently open.
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), JsonError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b) if b == byte => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(_) => Err(JsonError::UnexpectedChar(self.pos)),
+            None => Err(JsonError::UnexpectedEof),
+        }
+    }
+
+    /// Consumes `byte` if it is next, returning whether it was.
+    fn eat(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, JsonError> {
+        self.skip_whitespace();
+        match self.peek() {
+            None => Err(JsonError::UnexpectedEof),
+            Some(b'"') => self.string().map(Value::String),
+            Some(b'[' | b'{') => {
+                if self.depth == MAX_DEPTH {
+                    return Err(JsonError::TooDeep(self.pos));
+                }
+                self.depth += 1;
+                let value = if self.peek() == Some(b'[') {
+                    self.array()
+                } else {
+                    self.object()
+                };
+                self.depth -= 1;
+                value
+            }
+            Some(b'0'..=b'9') => self.number(),
+            Some(b'-') => Err(JsonError::UnsupportedNumber(self.pos)),
+            Some(b'n' | b't' | b'f') => {
+                let rest = &self.input[self.pos..];
+                let (literal, value) = [
+                    ("null", Value::Null),
+                    ("true", Value::Bool(true)),
+                    ("false", Value::Bool(false)),
+                ]
+                .into_iter()
+                .find(|(literal, _)| rest.starts_with(literal))
+                .ok_or(JsonError::UnexpectedChar(self.pos))?;
+                self.pos += literal.len();
+                Ok(value)
+            }
+            Some(_) => Err(JsonError::UnexpectedChar(self.pos)),
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, JsonError> {
+        let start = self.pos;
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+        if matches!(self.peek(), Some(b'.' | b'e' | b'E')) {
+            return Err(JsonError::UnsupportedNumber(start));
+        }
+        self.input[start..self.pos]
+            .parse()
+            .map(Value::Number)
+            .map_err(|_| JsonError::UnsupportedNumber(start))
+    }
+
+    fn array(&mut self) -> Result<Value, JsonError> {
+        self.expect(b'[')?;
+        let mut items = Vec::new();
+        if self.eat(b']') {
+            return Ok(Value::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            if self.eat(b']') {
+                return Ok(Value::Array(items));
+            }
+            self.expect(b',')?;
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, JsonError> {
+        self.expect(b'{')?;
+        let mut fields = Vec::new();
+        if self.eat(b'}') {
+            return Ok(Value::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.expect(b':')?;
+            fields.push((key, self.value()?));
+            if self.eat(b'}') {
+                return Ok(Value::Object(fields));
+            }
+            self.expect(b',')?;
+        }
+    }
+
+    fn string(&mut self) -> Result<String, JsonError> {
+        self.expect(b'"')?;
+        let mut s = String::new();
+        let mut chars = self.input[self.pos..].char_indices();
+        while let Some((offset, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += offset + 1;
+                    return Ok(s);
+                }
+                '\\' => {
+                    let escaped = match chars.next() {
+                        Some((_, '"')) => '"',
+                        Some((_, '\\')) => '\\',
+                        Some((_, '/')) => '/',
+                        Some((_, 'n')) => '\n',
+                        Some((_, 't')) => '\t',
+                        Some((_, 'r')) => '\r',
+                        Some((_, 'b')) => '\u{8}',
+                        Some((_, 'f')) => '\u{c}',
+                        Some((_, 'u')) => {
+                            let error = JsonError::UnexpectedChar(self.pos + offset);
+                            let unit = hex_escape(&mut chars).ok_or(error)?;
+                            let code = if (0xD800..0xDC00).contains(&unit) {
+                                // A high surrogate must be followed by an
+                                // escaped low surrogate.
+                                let low = match (chars.next(), chars.next()) {
+                                    (Some((_, '\\')), Some((_, 'u'))) => hex_escape(&mut chars),
+                                    _ => None,
+                                }
+                                .filter(|low| (0xDC00..0xE000).contains(low))
+                                .ok_or(error)?;
+                                0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00)
+                            } else {
+                                unit
+                            };
+                            char::from_u32(code).ok_or(error)?
+                        }
+                        Some(_) => return Err(JsonError::UnexpectedChar(self.pos + offset)),
+                        None => return Err(JsonError::UnexpectedEof),
+                    };
+                    s.push(escaped);
+                }
+                c if (c as u32) < 0x20 => return Err(JsonError::UnexpectedChar(self.pos + offset)),
+                c => s.push(c),
+            }
+        }
+        Err(JsonError::UnexpectedEof)
+    }
+}
+
+/// Reads the four hex digits of a `\u` escape, or `None` if they are missing or
+/// not all ASCII hex digits.
+fn hex_escape(chars: &mut std::str::CharIndices<'_>) -> Option<u32> {
+    (0..4).try_fold(0, |code, _| {
+        let (_, c) = chars.next()?;
+        Some(code * 16 + c.to_digit(16)?)
+    })
+}
@@ -0,0 +1,52 @@
+//! LSIF-style monikers for impl methods.
+//!
+//! An LSIF moniker is a scheme plus an identifier, written here as
+//! `<scheme>:<identifier>`. The scheme is always [`SCHEME`], and the
+//! identifier is the package name followed by the method's Rust path, as
+//! rendered by [`ImplDescriptor`]'s `Display` impl:
+//!
+//! ```text
+//! rust:minimal-scip-issue:<Scalar as Neg>::neg
+//! rust:minimal-scip-issue:<&Point as Mul<&Scalar>>::mul
+//! rust:minimal-scip-issue:Scalar::checked_neg
+//! ```
+//!
+//! Like rust-analyzer symbols, and unlike verus-analyzer's, the Rust path
+//! keeps the full Self type and trait reference, so no two impls share a
+//! moniker.
+
+use super::ImplDescriptor;
+
+/// The scheme of every moniker built here.
+pub const SCHEME: &str = "rust";
+
+/// Builds the LSIF moniker of an impl method.
+pub fn lsif_moniker(descriptor: &ImplDescriptor) -> String {
+    format!("{}:{}:{}", SCHEME, env!("CARGO_PKG_NAME"), descriptor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_lsif_moniker() {
+        let neg = ImplDescriptor::new("Scalar", Some("Neg"), &[], "neg");
+        assert_eq!(
+            lsif_moniker(&neg),
+            "rust:minimal-scip-issue:<Scalar as Neg>::neg"
+        );
+        assert_eq!(
+            lsif_moniker(&ImplDescriptor::inherent("Scalar", "checked_neg")),
+            "rust:minimal-scip-issue:Scalar::checked_neg"
+        );
+    }
+
+    #[test]
+    fn test_lsif_monikers_are_unique() {
+        let impls = crate::all_crate_impls();
+        let monikers: HashSet<String> = impls.iter().map(lsif_moniker).collect();
+        assert_eq!(monikers.len(), impls.len());
+    }
+}
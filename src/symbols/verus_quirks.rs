@@ -0,0 +1,114 @@
+//! Predicates for the known ways verus-analyzer symbols diverge from
+//! rust-analyzer's.
+//!
+//! Each predicate says whether a quirk affects the verus-analyzer symbol of an
+//! impl method, and names the crate-root Cases that demonstrate it. rust-analyzer
+//! is affected by none of them.
+
+use super::{adt_name, find_duplicate_symbols, strip_generics, ImplDescriptor, SymbolFormat};
+
+/// Whether the Self type is a reference, which verus-analyzer leaves out of
+/// the symbol (Cases 2, 5 and 30): `&Scalar`'s `neg` is just `Neg#neg().`.
+pub fn drops_reference_self(descriptor: &ImplDescriptor) -> bool {
+    descriptor.self_ty.trim_start().starts_with('&')
+}
+
+/// Whether the Self type is not an ADT, so verus-analyzer leaves it out of the
+/// symbol.
+///
+/// This covers references as in [`drops_reference_self`], but also type
+/// parameters (Case 12), tuples (Case 23), arrays (Case 24), primitives
+/// (Case 39) and trait objects (Case 50).
+pub fn drops_self_type(descriptor: &ImplDescriptor) -> bool {
+    adt_name(&descriptor.self_ty).is_none()
+}
+
+/// Whether verus-analyzer strips generic arguments the impl depends on, from
+/// either the trait (Case 3's `Mul<&Scalar>`) or the Self type (Case 4's
+/// `Container<TypeA>`, Case 8's `Vector<N>`, Case 49's `Scalar<i64>`).
+pub fn loses_generic_args(descriptor: &ImplDescriptor) -> bool {
+    let self_ty = descriptor.self_ty.trim();
+    let self_generic = !drops_self_type(descriptor) && strip_generics(self_ty) != self_ty;
+    !descriptor.trait_args.is_empty() || self_generic
+}
+
+/// Whether the verus-analyzer symbol of `impls[idx]` is shared with another
+/// impl in `impls`, as in Cases 3 and 4.
+///
+/// Impls whose rust-analyzer symbols are shared too are duplicates rather than
+/// a quirk, and are not reported.
+pub fn collides(impls: &[ImplDescriptor], idx: usize) -> bool {
+    let in_group = |format| {
+        find_duplicate_symbols(impls, format)
+            .iter()
+            .any(|(_, members)| members.contains(&idx))
+    };
+    in_group(SymbolFormat::VerusAnalyzer) && !in_group(SymbolFormat::RustAnalyzer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn neg(self_ty: &str) -> ImplDescriptor {
+        ImplDescriptor::new(self_ty, Some("Neg"), &[], "neg")
+    }
+
+    #[test]
+    fn test_drops_reference_self() {
+        let impls = crate::all_crate_impls();
+        assert!(!drops_reference_self(&impls[0]));
+        assert!(drops_reference_self(&impls[1]));
+        assert!(!drops_reference_self(&neg("(Scalar, Scalar)")));
+    }
+
+    #[test]
+    fn test_drops_self_type() {
+        assert!(!drops_self_type(&neg("Scalar")));
+        assert!(!drops_self_type(&neg("Box<Scalar>")));
+        for self_ty in [
+            "&Scalar",
+            "T",
+            "(Scalar, Scalar)",
+            "[Scalar; 2]",
+            "i32",
+            "dyn Shape",
+        ] {
+            assert!(drops_self_type(&neg(self_ty)), "{}", self_ty);
+        }
+    }
+
+    #[test]
+    fn test_loses_generic_args() {
+        let impls = crate::all_crate_impls();
+        assert!(!loses_generic_args(&impls[0]));
+        assert!(loses_generic_args(&impls[2]));
+        assert!(loses_generic_args(&impls[4]));
+        assert!(loses_generic_args(&neg("Vector<N>")));
+        // A dropped Self type cannot lose its generics on top of that.
+        assert!(!loses_generic_args(&neg("&Container<TypeA>")));
+    }
+
+    #[test]
+    fn test_collides() {
+        let impls = crate::all_crate_impls();
+        assert!(!collides(&impls, 0));
+        assert!(collides(&impls, 2));
+        assert!(collides(&impls, 3));
+        assert!(collides(&impls, 4));
+    }
+
+    #[test]
+    fn test_every_collision_has_a_quirk() {
+        let impls = crate::all_crate_impls();
+        for (idx, descriptor) in impls.iter().enumerate() {
+            if collides(&impls, idx) {
+                assert!(
+                    drops_self_type(descriptor) || loses_generic_args(descriptor),
+                    "{} collides without a known quirk",
+                    descriptor
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Deterministic generation of random impl descriptors for property tests.
+//!
+//! The generator is seeded, so a failing case can be reproduced from the seed
+//! alone, and needs no external fuzzing crate.
+
+use crate::ImplDescriptor;
+
+/// Traits the generated impls implement, paired with their method.
+const TRAITS: &[(&str, &str)] = &[
+    ("Neg", "neg"),
+    ("Mul", "mul"),
+    ("Add", "add"),
+    ("From", "from"),
+    ("Index", "index"),
+];
+
+/// Types the generated trait arguments and generic parameters draw from.
+const ARGS: &[&str] = &[
+    "i32",
+    "Scalar",
+    "&Scalar",
+    "Point",
+    "&Point",
+    "(i32, i32)",
+    "[Scalar; 2]",
+    "Container<TypeA>",
+];
+
+/// A SplitMix64 pseudo-random number generator.
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `0..n`.
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub(crate) fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+/// Generates `count` trait impl descriptors with pairwise distinct, owned ADT
+/// Self types, some of them generic, e.g. `Gen3` or `Gen7<&Point, i32>`.
+pub(crate) fn descriptors(rng: &mut Rng, count: usize) -> Vec<ImplDescriptor> {
+    (0..count)
+        .map(|idx| {
+            let params: Vec<&str> = (0..rng.below(3)).map(|_| *rng.pick(ARGS)).collect();
+            let self_ty = if params.is_empty() {
+                format!("Gen{}", idx)
+            } else {
+                format!("Gen{}<{}>", idx, params.join(", "))
+            };
+            let &(trait_ty, method) = rng.pick(TRAITS);
+            let args: Vec<&str> = (0..rng.below(2)).map(|_| *rng.pick(ARGS)).collect();
+            ImplDescriptor::new(&self_ty, Some(trait_ty), &args, method)
+        })
+        .collect()
+}
+
+/// Returns a copy of `descriptor` with a reference to its Self type as Self.
+pub(crate) fn by_reference(descriptor: &ImplDescriptor) -> ImplDescriptor {
+    ImplDescriptor {
+        self_ty: format!("&{}", descriptor.self_ty),
+        ..descriptor.clone()
+    }
+}
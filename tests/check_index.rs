@@ -0,0 +1,29 @@
+//! Smoke test for `examples/check_index.rs`, whose code is compiled in here.
+
+#[allow(dead_code)]
+#[path = "../examples/check_index.rs"]
+mod check_index;
+
+use minimal_scip_issue::scip::to_scip_index;
+use minimal_scip_issue::SymbolFormat;
+
+#[test]
+fn test_generated_index_has_no_mismatches() {
+    for format in SymbolFormat::ALL {
+        let path = std::env::temp_dir().join(format!(
+            "minimal-scip-issue-{}-{}.scip",
+            format.tool_name(),
+            std::process::id()
+        ));
+        std::fs::write(&path, to_scip_index(format).encode_to_vec()).unwrap();
+        let mismatches = check_index::check(&path, format);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(mismatches, Ok(Vec::new()));
+    }
+}
+
+#[test]
+fn test_unreadable_index() {
+    let path = std::path::Path::new("does-not-exist.scip");
+    assert!(check_index::check(path, SymbolFormat::RustAnalyzer).is_err());
+}
@@ -0,0 +1,36 @@
+//! Runs the `scip-symbols` binary and checks its JSON output.
+
+use std::process::Command;
+
+use minimal_scip_issue::all_crate_impls;
+use minimal_scip_issue::symbols::symbol_report_json;
+
+fn run(args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_scip-symbols"))
+        .args(args)
+        .output()
+        .expect("failed to run scip-symbols")
+}
+
+#[test]
+fn test_format_json() {
+    let output = run(&["--format", "json"]);
+    assert!(output.status.success());
+    // The library's unit tests parse `symbol_report_json` and check its fields,
+    // so matching it exactly covers the structure of the output.
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout, symbol_report_json(&all_crate_impls()));
+}
+
+#[test]
+fn test_format_json_fail_on_duplicates() {
+    let output = run(&["--format", "json", "--fail-on-duplicates"]);
+    assert!(!output.status.success());
+    assert!(!output.stdout.is_empty());
+}
+
+#[test]
+fn test_unknown_format() {
+    let output = run(&["--format", "xml"]);
+    assert_eq!(output.status.code(), Some(2));
+}
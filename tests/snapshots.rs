@@ -0,0 +1,72 @@
+//! Compares every impl's symbols against the golden file `tests/snapshots/symbols.snap`.
+//!
+//! Run with `UPDATE_SNAPSHOTS=1` to rewrite the golden file after an intended
+//! change, then review the diff of the `.snap` file like any other change.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use minimal_scip_issue::{all_crate_impls, SymbolFormat};
+
+fn snapshot_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots/symbols.snap")
+}
+
+/// Renders one block per impl: its Rust path, then its symbol in each format.
+fn render() -> String {
+    let mut snapshot = String::new();
+    for descriptor in all_crate_impls() {
+        snapshot.push_str(&format!("{}\n", descriptor));
+        for format in SymbolFormat::ALL {
+            snapshot.push_str(&format!(
+                "  {}: {}\n",
+                format.tool_name(),
+                descriptor.to_symbol(format)
+            ));
+        }
+    }
+    snapshot
+}
+
+/// Lists the lines that differ between the two snapshots, as `-expected` and
+/// `+actual` pairs prefixed with their line number.
+fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut out = String::new();
+    for idx in 0..expected.len().max(actual.len()) {
+        let (old, new) = (expected.get(idx), actual.get(idx));
+        if old == new {
+            continue;
+        }
+        out.push_str(&format!("line {}:\n", idx + 1));
+        if let Some(old) = old {
+            out.push_str(&format!("  -{}\n", old));
+        }
+        if let Some(new) = new {
+            out.push_str(&format!("  +{}\n", new));
+        }
+    }
+    out
+}
+
+#[test]
+fn test_symbol_snapshot() {
+    let path = snapshot_path();
+    let actual = render();
+    if env::var_os("UPDATE_SNAPSHOTS").is_some_and(|value| value == "1") {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("cannot read {}: {}", path.display(), err));
+    if expected != actual {
+        panic!(
+            "symbols differ from {} (rerun with UPDATE_SNAPSHOTS=1 to accept):\n{}",
+            path.display(),
+            diff(&expected, &actual)
+        );
+    }
+}